@@ -27,7 +27,7 @@ impl Anomaly {
     ///
     pub fn eccentric(
         self,
-        shape: crate::orbit::Type,
+        shape: orbit::Type,
         day: f64,
         orbital_eccentricity: f64,
         peri: Perihelion,
@@ -100,7 +100,7 @@ impl Anomaly {
                     hdx = x0 / x1;
 
                     // Hk+1 = Hk + (M-esinh(Hk)+Hk)/(ecosh(Hk)-1)
-                    hx0 = hx0 + hdx;
+                    hx0 += hdx;
                 }
 
                 let mean_motion = MeanMotion.by(day, peri, orbital_period);
@@ -132,7 +132,7 @@ impl Anomaly {
                     zdx = x0 / x1;
 
                     // En = En + En+1
-                    zx0 = zx0 + zdx;
+                    zx0 += zdx;
                 }
 
                 let mean_motion = MeanMotion.by(day, peri, orbital_period);
@@ -167,7 +167,7 @@ impl Anomaly {
     ///
     pub fn truly(
         self,
-        shape: crate::orbit::Type,
+        shape: orbit::Type,
         day: f64,
         orbital_eccentricity: f64,
         peri: Perihelion,
@@ -187,7 +187,7 @@ impl Anomaly {
 
                 let mean_motion = MeanMotion.by(day, peri, orbital_period);
 
-                theta = theta + mean_motion;
+                theta += mean_motion;
 
                 theta
             }
@@ -0,0 +1,455 @@
+use std::{fmt, str::FromStr};
+
+use chrono::Duration;
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{
+    datetime::PlanetaryDateTime,
+    format::{ParseError, Parsed},
+    kepler::{Body, Clock},
+};
+
+/// The calendar of [`crate::kepler::Date`], years since the body's epoch split into months of equal solar longitude
+pub const CALENDAR: &str = "ls";
+
+/// The nanoseconds in an earth day
+const DAY: i128 = 86_400_000_000_000;
+
+/// The nanoseconds from the start of the julian period to J2000
+const J2000: i128 = 2_451_545 * DAY;
+
+/// The decimals of a written julian date, a tenth of a nanosecond so every nanosecond reads back
+const DECIMALS: usize = 15;
+
+/// The units of the last decimal in a day
+const UNITS: i128 = 1_000_000_000_000_000;
+
+/// The characters that end or mark the parts of a timestamp, escaped in names as `%` and two hex digits
+const RESERVED: [char; 5] = ['%', ':', '[', ']', '@'];
+
+#[derive(Display, Debug, Clone, PartialEq, Error)]
+/// This is the collection of problems when reading a [`Timestamp`]
+pub enum TimestampError {
+    /// {0}
+    Parse(#[from] ParseError),
+    /// the timestamp is of `{0}`, not `{1}`
+    Body(String, String),
+    /// `{0}` is not a known calendar
+    Calendar(String),
+    /// the fields `{0}` do not match the julian date, which is `{1}`
+    Inconsistent(String, String),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// This structure is the canonical text of a planetary date time, for exchanging between services.
+///
+/// ```text
+/// mars:ls:36-01-12T15:54:31.188072301[HT]@2459264.372330000157708
+/// └──┘ └┘ └───────┘ └────────────────┘ └┘ └─────────────────────┘
+/// body │  year-month-sol    time      zone  julian date (TT), optional
+///    calendar
+/// ```
+///
+/// | Part | Written as |
+/// |------|------------|
+/// | body | the lowercase [`Body::name`] with `%`, `:`, `[`, `]` and `@` escaped as `%25`, `%3A`, `%5B`, `%5D` and `%40`, up to the first `:` |
+/// | calendar | [`CALENDAR`], up to the next `:` |
+/// | year | the signed year of the body |
+/// | month | two digits, the month of the year |
+/// | sol | at least two digits, the sol of the month from 1 |
+/// | time | `hh:mm:ss` on the zone's clock, with 9 digits of the second (1 to 9 are read) |
+/// | zone | [`Clock::identifier`] in brackets, like `HT`, `MTC+2` or `US/Pacific`, escaped like the body |
+/// | julian date | `@` and the julian date (TT) with 15 decimals (1 to 15 are read) |
+///
+/// The julian date keeps the instant to the nanosecond, so it is written by default.
+/// The clock is read from a julian date in an `f64`, so the digits of the second are good to
+/// about 10 µs, not to the nanosecond. Without the julian date the instant is found from the
+/// fields, to within a millisecond. With it the fields are checked against it.
+///
+/// ```rust
+/// use rust_solar::canonical::Timestamp;
+/// use rust_solar::datetime::PlanetaryDateTime;
+/// use rust_solar::planets::mars::{Mars, Martian};
+///
+/// let landing = PlanetaryDateTime::new(Mars, 2459264.37233);
+/// let text = landing.timestamp(&Martian::MTCp2).to_string();
+///
+/// let timestamp = text.parse::<Timestamp>().unwrap();
+/// let (read, zone) = PlanetaryDateTime::from_timestamp::<Martian>(Mars, &timestamp).unwrap();
+///
+/// assert_eq!(landing, read);
+/// assert_eq!(Martian::MTCp2, zone);
+/// assert_eq!(text, timestamp.to_string());
+/// ```
+pub struct Timestamp {
+    /// ### The name of the body, not escaped
+    pub body: String,
+    /// ### The calendar of the date
+    pub calendar: String,
+    /// ### The year of the body
+    pub year: i32,
+    /// ### The month of the year
+    pub month: u8,
+    /// ### The sol of the month
+    pub sol: u32,
+    /// ### The hour on the zone's clock
+    pub hour: u32,
+    /// ### The minute on the zone's clock
+    pub minute: u32,
+    /// ### The second on the zone's clock
+    pub second: u32,
+    /// ### The nanoseconds into the second
+    pub nanosecond: u32,
+    /// ### The zone whose clock reads the time, not escaped
+    pub zone: String,
+    /// ### The nanoseconds since J2000 (TT), written as a julian date
+    pub nanoseconds: Option<i128>,
+}
+
+impl Timestamp {
+    /// This method writes the fields without the julian date.
+    fn fields(&self) -> String {
+        format!(
+            "{}:{}:{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}[{}]",
+            escape(&self.body),
+            self.calendar,
+            self.year,
+            self.month,
+            self.sol,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+            escape(&self.zone)
+        )
+    }
+}
+
+/// This function escapes the characters of a name that would end its part of a timestamp.
+fn escape(name: &str) -> String {
+    name.chars()
+        .map(|c| match RESERVED.contains(&c) {
+            true => format!("%{:02X}", c as u32),
+            false => c.to_string(),
+        })
+        .collect()
+}
+
+/// This function reads back a name escaped at a byte of the timestamp.
+fn unescape(text: &str, at: usize, name: &'static str) -> Result<String, ParseError> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '%' => {
+                let c = text
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .map(char::from)
+                    .filter(|c| RESERVED.contains(c))
+                    .ok_or(ParseError::Value(name, at + index))?;
+
+                unescaped.push(c);
+                chars.nth(1);
+            }
+            c => unescaped.push(c),
+        }
+    }
+
+    Ok(unescaped)
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.fields())?;
+
+        if let Some(nanoseconds) = self.nanoseconds {
+            // the days and their rest are kept apart so far instants do not overflow
+            let total = nanoseconds.saturating_add(J2000);
+            let (days, rest) = (
+                total.unsigned_abs() / DAY as u128,
+                total.unsigned_abs() % DAY as u128,
+            );
+
+            // rounded to the nearest unit of the last decimal
+            let units = (rest * UNITS as u128 * 2 + DAY as u128) / (DAY as u128 * 2);
+            let (days, units) = match units == UNITS as u128 {
+                true => (days + 1, 0),
+                false => (days, units),
+            };
+            let sign = match total < 0 && (days, units) != (0, 0) {
+                true => "-",
+                false => "",
+            };
+
+            write!(formatter, "@{sign}{days}.{units:0width$}", width = DECIMALS)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// This structure reads a text a part at a time, keeping where it is for errors.
+struct Reader<'a> {
+    text: &'a str,
+    at: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// This method reads up to a character, which is skipped.
+    fn until(&mut self, end: char, name: &'static str) -> Result<&'a str, ParseError> {
+        let rest = &self.text[self.at..];
+        let length = rest
+            .find(end)
+            .ok_or(ParseError::Literal(end.to_string(), self.text.len()))?;
+
+        if length == 0 {
+            return Err(ParseError::Value(name, self.at));
+        }
+
+        self.at += length + end.len_utf8();
+        Ok(&rest[..length])
+    }
+
+    /// This method reads a character.
+    fn literal(&mut self, literal: char) -> Result<(), ParseError> {
+        match self.text[self.at..].starts_with(literal) {
+            true => {
+                self.at += literal.len_utf8();
+                Ok(())
+            }
+            false => Err(ParseError::Literal(literal.to_string(), self.at)),
+        }
+    }
+
+    /// This method checks for a character, reading it when it is there.
+    fn optional(&mut self, literal: char) -> bool {
+        self.literal(literal).is_ok()
+    }
+
+    /// This method reads digits, between a fewest and most.
+    fn digits(
+        &mut self,
+        fewest: usize,
+        most: usize,
+        name: &'static str,
+    ) -> Result<&'a str, ParseError> {
+        let rest = &self.text[self.at..];
+        let length = rest
+            .bytes()
+            .take(most)
+            .take_while(u8::is_ascii_digit)
+            .count();
+
+        if length < fewest {
+            return Err(ParseError::Value(name, self.at));
+        }
+
+        self.at += length;
+        Ok(&rest[..length])
+    }
+
+    /// This method reads a number within a range.
+    fn number(
+        &mut self,
+        fewest: usize,
+        most: usize,
+        range: (i64, i64),
+        name: &'static str,
+    ) -> Result<i64, ParseError> {
+        let at = self.at;
+        let number = self
+            .digits(fewest, most, name)?
+            .parse::<i64>()
+            .map_err(|_| ParseError::Value(name, at))?;
+
+        match (range.0..=range.1).contains(&number) {
+            true => Ok(number),
+            false => Err(ParseError::Range(name, number as f64)),
+        }
+    }
+
+    /// This method reads a fraction into a number of units, like the nanoseconds of `.5`.
+    fn fraction(&mut self, most: usize, name: &'static str) -> Result<i128, ParseError> {
+        let digits = self.digits(1, most, name)?;
+
+        Ok(format!("{digits:0<most$}")
+            .parse::<i128>()
+            .unwrap_or_default())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut reader = Reader { text, at: 0 };
+
+        let at = reader.at;
+        let body = unescape(reader.until(':', "body")?, at, "body")?;
+        let calendar = reader.until(':', "calendar")?.to_string();
+
+        let negative = reader.optional('-');
+        let year = reader.number(1, 10, (0, i32::MAX as i64), "year")? as i32;
+        let year = if negative { -year } else { year };
+        reader.literal('-')?;
+        let month = reader.number(1, 2, (1, 99), "month")? as u8;
+        reader.literal('-')?;
+        let sol = reader.number(1, 9, (1, 999_999_999), "sol")? as u32;
+
+        reader.literal('T')?;
+        let hour = reader.number(2, 2, (0, 23), "hour")? as u32;
+        reader.literal(':')?;
+        let minute = reader.number(2, 2, (0, 59), "minute")? as u32;
+        reader.literal(':')?;
+        let second = reader.number(2, 2, (0, 59), "second")? as u32;
+        let nanosecond = match reader.optional('.') {
+            true => reader.fraction(9, "nanoseconds")? as u32,
+            false => 0,
+        };
+
+        reader.literal('[')?;
+        let at = reader.at;
+        let zone = unescape(reader.until(']', "zone")?, at, "zone")?;
+
+        let nanoseconds = match reader.optional('@') {
+            true => {
+                let negative = reader.optional('-');
+                let days = reader
+                    .digits(1, 12, "julian date")?
+                    .parse::<i128>()
+                    .unwrap_or_default();
+                let units = match reader.optional('.') {
+                    true => reader.fraction(DECIMALS, "julian date")?,
+                    false => 0,
+                };
+                let units = match negative {
+                    true => -(days * UNITS + units),
+                    false => days * UNITS + units,
+                };
+
+                // rounded to the nearest nanosecond, julian dates too far for the nanoseconds are refused
+                let nanoseconds = units
+                    .checked_mul(DAY * 2)
+                    .and_then(|units| units.checked_add(UNITS))
+                    .map(|units| units.div_euclid(UNITS * 2) - J2000);
+
+                match nanoseconds {
+                    Some(nanoseconds) => Some(nanoseconds),
+                    None => {
+                        return Err(ParseError::Range(
+                            "julian date",
+                            units as f64 / UNITS as f64,
+                        ))
+                    }
+                }
+            }
+            false => None,
+        };
+
+        if reader.at < text.len() {
+            return Err(ParseError::Trailing(
+                text[reader.at..].to_string(),
+                reader.at,
+            ));
+        }
+
+        Ok(Self {
+            body,
+            calendar,
+            year,
+            month,
+            sol,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            zone,
+            nanoseconds,
+        })
+    }
+}
+
+impl<B: Body + Clone> PlanetaryDateTime<B> {
+    /// This method gets the canonical timestamp of the date time on a zone's clock.
+    pub fn timestamp<Z: Clock>(&self, zone: &Z) -> Timestamp {
+        // the clock's time with the fraction of the second, as far as an f64 julian date reads it
+        let seconds = zone.hours(self.julian_date()) * 3600.0;
+        let nanoseconds = ((seconds - seconds.floor()) * 1e9).round() as u32;
+        let written = seconds.floor() + (nanoseconds / 1_000_000_000) as f64;
+
+        // the date is the one at the start of the written second, which the fields round to
+        let back = (written - seconds) * zone.day_length() * DAY as f64 / 86400.0;
+        let start = Self::from_nanoseconds(self.body.clone(), self.nanoseconds() + back as i128);
+        let fields = start.fields(zone);
+
+        Timestamp {
+            body: self.body.name().to_lowercase(),
+            calendar: CALENDAR.to_string(),
+            year: fields.year,
+            month: fields.month,
+            sol: fields.sol,
+            hour: fields.time.hour as u32,
+            minute: fields.time.minute.into(),
+            second: fields.time.second.into(),
+            nanosecond: nanoseconds % 1_000_000_000,
+            zone: zone.identifier(),
+            nanoseconds: Some(self.nanoseconds()),
+        }
+    }
+
+    /// This method finds the date time of a canonical timestamp, with the zone it is in.
+    pub fn from_timestamp<Z: Clock + Clone + FromStr>(
+        body: B,
+        timestamp: &Timestamp,
+    ) -> Result<(Self, Z), TimestampError> {
+        if !timestamp.body.eq_ignore_ascii_case(&body.name()) {
+            return Err(TimestampError::Body(
+                timestamp.body.clone(),
+                body.name().to_lowercase(),
+            ));
+        }
+        if timestamp.calendar != CALENDAR {
+            return Err(TimestampError::Calendar(timestamp.calendar.clone()));
+        }
+
+        let zone = timestamp
+            .zone
+            .parse::<Z>()
+            .map_err(|_| ParseError::Unknown(timestamp.zone.clone(), "zone"))?;
+
+        if let Some(nanoseconds) = timestamp.nanoseconds {
+            let found = Self::from_nanoseconds(body, nanoseconds);
+            let expected = Timestamp {
+                body: timestamp.body.clone(),
+                zone: timestamp.zone.clone(),
+                ..found.timestamp(&zone)
+            };
+
+            return match expected.fields() == timestamp.fields() {
+                true => Ok((found, zone)),
+                false => Err(TimestampError::Inconsistent(
+                    timestamp.fields(),
+                    expected.fields(),
+                )),
+            };
+        }
+
+        let parsed = Parsed {
+            year: Some(timestamp.year),
+            month: Some(timestamp.month),
+            sol: Some(timestamp.sol),
+            hour: Some(timestamp.hour),
+            minute: Some(timestamp.minute),
+            second: Some(timestamp.second),
+            ..Default::default()
+        };
+        let found = Self::from_parsed(body, &parsed, zone.clone())?;
+        // the fraction is of the zone's clock seconds, which are not earth seconds off earth
+        let fraction = (timestamp.nanosecond as f64 * zone.day_length()).round() as i64;
+
+        Ok((found + Duration::nanoseconds(fraction), zone))
+    }
+}
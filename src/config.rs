@@ -0,0 +1,463 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use displaydoc::Display;
+use julian_day_converter::JULIAN_DAY_UNIX_EPOCH_DAYS;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{
+    conversions::radians_in_circle,
+    julian::JD2NOON,
+    kepler::{Body, Clock, Date, HourType, Time, TimeZone},
+    orbit::Perihelion,
+    planets::{EARTH_ORBITAL_PERIOD, EARTH_ROTATIONAL_PERIOD},
+};
+
+#[derive(Display, Debug, Error)]
+/// This is the collection of problems when loading a body from a configuration
+pub enum ConfigError {
+    /// could not read the file: {0}
+    Io(#[from] std::io::Error),
+    /// the file extension must be `toml` or `json`
+    Format,
+    /// `{0}`: {1}
+    Parse(String, String),
+    /// `{0}`: {1}
+    Field(String, &'static str),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// This data structure is the star a hypothetical body orbits.
+pub struct Star {
+    /// ### The name of the star
+    pub name: String,
+    /// ### The mass in solar masses
+    #[serde(default = "solar")]
+    pub mass: f64,
+    /// ### The brightness in solar luminosities
+    #[serde(default = "solar")]
+    pub luminosity: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// This data structure is a time zone of a hypothetical body.
+pub struct ZoneConfig {
+    /// ### The short code of the zone, like `AMT`
+    pub code: String,
+    /// ### The name of the zone, like `Amazonis Time`
+    pub name: String,
+    /// ### The hours (of a 24 hour local day) ahead of the prime meridian
+    pub offset: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// This data structure is the configuration of a hypothetical body.
+///
+/// ```toml
+/// name = "Arrakis"
+/// epoch = 2451545.0
+/// semimajor = 2.3
+/// eccentricity = 0.05
+/// rotational_period = 80000.0
+/// obliquity = 12.0
+/// months = ["Ix", "Harg", "Tabr"]
+/// zones = [{ code = "AT", name = "Arrakeen Time", offset = 0.0 }]
+///
+/// [host]
+/// name = "Canopus"
+/// mass = 8.0
+/// ```
+pub struct Config {
+    /// ### The name of the body
+    pub name: String,
+    /// ### The star the body orbits
+    pub host: Star,
+    /// ### The julian date of a vernal equinox (Ls 0) at the prime meridian's midnight
+    pub epoch: f64,
+    /// ### The average distance from the star in AU
+    pub semimajor: f64,
+    /// ### The deviation of the orbit from a circle
+    pub eccentricity: f64,
+    /// ### The seconds in a day of the body
+    pub rotational_period: f64,
+    /// ### The tilt of the equator against the orbit in degrees
+    pub obliquity: f64,
+    /// ### The solar longitude of the perihelion in degrees
+    #[serde(default)]
+    pub perihelion: f64,
+    /// ### The tilt of the orbit against the ecliptic in degrees
+    #[serde(default)]
+    pub inclination: f64,
+    /// ### The longitude of the ascending node in degrees
+    #[serde(default)]
+    pub ascending_node: f64,
+    /// ### The argument of perihelion in degrees
+    #[serde(default)]
+    pub argument_of_perihelion: f64,
+    /// ### The names of the months, which split the solar longitude evenly
+    pub months: Vec<String>,
+    /// ### The names of the seasons from Ls 0, which split the solar longitude evenly
+    #[serde(default = "seasons")]
+    pub seasons: Vec<String>,
+    /// ### The time zones of the body
+    pub zones: Vec<ZoneConfig>,
+}
+
+impl Config {
+    /// This method loads a body from a `.toml` or `.json` file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Hypothetical, ConfigError> {
+        let text = fs::read_to_string(&path)?;
+
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::toml(&text),
+            Some("json") => Self::json(&text),
+            _ => Err(ConfigError::Format),
+        }
+    }
+
+    /// This method loads a body from TOML.
+    pub fn toml(text: &str) -> Result<Hypothetical, ConfigError> {
+        let config: Self = serde_path_to_error::deserialize(toml::Deserializer::new(text))
+            .map_err(|e| ConfigError::Parse(e.path().to_string(), e.inner().message().into()))?;
+
+        config.validate()
+    }
+
+    /// This method loads a body from JSON.
+    pub fn json(text: &str) -> Result<Hypothetical, ConfigError> {
+        let config: Self =
+            serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(text))
+                .map_err(|e| ConfigError::Parse(e.path().to_string(), e.inner().to_string()))?;
+
+        config.validate()
+    }
+
+    /// This method checks the values are physical and turns the configuration into a body.
+    pub fn validate(self) -> Result<Hypothetical, ConfigError> {
+        let field = |name: &str, reason| Err(ConfigError::Field(name.to_string(), reason));
+
+        if self.name.trim().is_empty() {
+            return field("name", "must not be empty");
+        }
+        if !(self.epoch.is_finite() && self.epoch > 0.0) {
+            return field("epoch", "must be a finite julian date greater than 0");
+        }
+        if !(self.host.mass.is_finite() && self.host.mass > 0.0) {
+            return field("host.mass", "must be finite and greater than 0");
+        }
+        if !(self.host.luminosity.is_finite() && self.host.luminosity >= 0.0) {
+            return field("host.luminosity", "must be finite and not negative");
+        }
+        if !(self.semimajor.is_finite() && self.semimajor > 0.0) {
+            return field("semimajor", "must be finite and greater than 0");
+        }
+        if !(0.0..1.0).contains(&self.eccentricity) {
+            return field("eccentricity", "must be at least 0 and less than 1");
+        }
+        if !(self.rotational_period.is_finite() && self.rotational_period > 0.0) {
+            return field("rotational_period", "must be finite and greater than 0");
+        }
+        if !(0.0..=180.0).contains(&self.obliquity) {
+            return field("obliquity", "must be between 0 and 180");
+        }
+        if !(0.0..360.0).contains(&self.perihelion) {
+            return field("perihelion", "must be at least 0 and less than 360");
+        }
+        if !self.inclination.is_finite() {
+            return field("inclination", "must be finite");
+        }
+        if !self.ascending_node.is_finite() {
+            return field("ascending_node", "must be finite");
+        }
+        if !self.argument_of_perihelion.is_finite() {
+            return field("argument_of_perihelion", "must be finite");
+        }
+        if self.months.is_empty() {
+            return field("months", "must name at least one month");
+        }
+        if self.seasons.is_empty() {
+            return field("seasons", "must name at least one season");
+        }
+        if self.zones.is_empty() {
+            return field("zones", "must have at least one zone");
+        }
+
+        let mut codes = HashSet::new();
+        for (index, zone) in self.zones.iter().enumerate() {
+            if !(-12.0..=12.0).contains(&zone.offset) {
+                return field(
+                    &format!("zones[{index}].offset"),
+                    "must be between -12 and 12",
+                );
+            }
+            if !codes.insert(zone.code.as_str()) {
+                return field(
+                    &format!("zones[{index}].code"),
+                    "is already used by another zone",
+                );
+            }
+        }
+
+        Ok(Hypothetical { config: self })
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This structure is a body loaded from a [`Config`].
+pub struct Hypothetical {
+    /// ### The validated configuration
+    pub config: Config,
+}
+
+impl Hypothetical {
+    /// This method gets the name of the month of a date, borrowed from the configuration.
+    pub fn name_of_month(&self, date: &Date) -> &str {
+        let months = &self.config.months;
+
+        &months[(date.month as usize).clamp(1, months.len()) - 1]
+    }
+
+    /// This method gets the time zones of the body.
+    pub fn zones(&self) -> Vec<Zone> {
+        self.config
+            .zones
+            .iter()
+            .map(|zone| Zone {
+                body: self.config.name.clone(),
+                code: zone.code.clone(),
+                name: zone.name.clone(),
+                offset: zone.offset,
+                epoch: self.config.epoch,
+                rotational_period: self.config.rotational_period,
+            })
+            .collect()
+    }
+
+    /// This method finds a time zone by its code or name.
+    pub fn zone(&self, code: &str) -> Option<Zone> {
+        self.zones()
+            .into_iter()
+            .find(|zone| zone.code == code || zone.name == code)
+    }
+}
+
+impl Body for Hypothetical {
+    fn epoch(&self) -> f64 {
+        self.config.epoch
+    }
+
+    fn name(&self) -> String {
+        self.config.name.clone()
+    }
+
+    fn month_name(&self, month: u8) -> String {
+        let date = Date {
+            month,
+            ..Date::default()
+        };
+
+        self.name_of_month(&date).to_string()
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        self.config.eccentricity
+    }
+
+    fn orbital_period(&self) -> f64 {
+        EARTH_ORBITAL_PERIOD
+            * (self.config.semimajor.powi(3) / self.config.host.mass).sqrt()
+            * EARTH_ROTATIONAL_PERIOD
+            / self.config.rotational_period
+    }
+
+    fn rotational_period(&self) -> f64 {
+        self.config.rotational_period
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        let e = self.config.eccentricity;
+        let peri = self.config.perihelion;
+
+        // the year starts at Ls 0, which is the true anomaly of -perihelion
+        let half = (-peri.to_radians() / 2.0).tan();
+        let eccentric = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * half).atan();
+        let mean = (eccentric - e * eccentric.sin()).rem_euclid(radians_in_circle());
+        let day = (1.0 - mean / radians_in_circle()) * self.orbital_period();
+        let months = self.config.months.len() as f64;
+
+        Perihelion {
+            month: (day, day + self.orbital_period() / months),
+            ls: (peri, peri + 360.0 / months),
+            perihelion: peri,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        self.config.semimajor
+    }
+
+    fn inclination(&self) -> f64 {
+        self.config.inclination
+    }
+
+    fn ascending_node(&self) -> f64 {
+        self.config.ascending_node
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        self.config.argument_of_perihelion
+    }
+
+    fn obliquity(&self) -> f64 {
+        self.config.obliquity
+    }
+
+    fn luminosity(&self) -> f64 {
+        self.config.host.luminosity
+    }
+
+    // the epoch is midnight at the equinox, so the prime meridian faces away from the sun
+    fn prime_meridian(&self) -> f64 {
+        let turns =
+            (self.config.epoch - JD2NOON) * EARTH_ROTATIONAL_PERIOD / self.sidereal_period();
+
+        match self.config.obliquity > 90.0 {
+            true => 180.0 + 360.0 * turns.fract(),
+            false => 180.0 - 360.0 * turns.fract(),
+        }
+    }
+
+    fn to_date(&mut self, julian_date: f64) -> Date {
+        let date = Date::default().compute(
+            julian_date,
+            self.epoch(),
+            self.rotational_period(),
+            self.perihelion_at(julian_date),
+            self.semimajor(),
+            self.orbital_eccentricity(),
+            self.orbital_period(),
+        );
+        let seasons = &self.config.seasons;
+        let season = (date.ls / (360.0 / seasons.len() as f64)) as usize;
+
+        Date {
+            season: seasons[season.min(seasons.len() - 1)].clone(),
+            ..date
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// This structure is a time zone of a [`Hypothetical`] body.
+pub struct Zone {
+    /// ### The name of the body
+    pub body: String,
+    /// ### The short code of the zone
+    pub code: String,
+    /// ### The name of the zone
+    pub name: String,
+    /// ### The hours ahead of the prime meridian
+    pub offset: f64,
+    /// ### The julian date of the prime meridian's midnight
+    pub epoch: f64,
+    /// ### The seconds in a day of the body
+    pub rotational_period: f64,
+}
+
+impl Clock for Zone {
+    fn day_length(&self) -> f64 {
+        self.rotational_period / EARTH_ROTATIONAL_PERIOD
+    }
+
+    fn hours(&self, julian_date: f64) -> f64 {
+        24.0 * ((julian_date - self.epoch) / self.day_length() + self.offset / 24.0).rem_euclid(1.0)
+    }
+
+    fn labels(&self, _julian_date: f64) -> (String, String, String) {
+        (
+            self.code.clone(),
+            self.name.clone(),
+            format!("{}{:+}", self.body, self.offset),
+        )
+    }
+}
+
+impl TimeZone for Zone {
+    fn millis(&self) -> f64 {
+        chrono::Utc::now().timestamp_millis() as f64
+    }
+
+    fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    fn julian_offset(&self) -> f64 {
+        JULIAN_DAY_UNIX_EPOCH_DAYS
+    }
+
+    fn julian_date_universal_time(&self) -> f64 {
+        self.julian_offset() + self.millis() / (EARTH_ROTATIONAL_PERIOD * 1000.0)
+    }
+
+    fn body_host_ratio(&self) -> f64 {
+        self.rotational_period / EARTH_ROTATIONAL_PERIOD
+    }
+
+    fn julian_date_terrestial_time(&self) -> f64 {
+        // leap seconds since January 1st, 2017
+        let leap_seconds = 37.0 + 32.184;
+
+        self.julian_date_universal_time() + leap_seconds / EARTH_ROTATIONAL_PERIOD
+    }
+
+    fn julian_date_2000_time(&self) -> f64 {
+        self.julian_date_terrestial_time() - JD2NOON
+    }
+
+    // the days since the epoch, shifted into the zone
+    fn day_date(&self) -> f64 {
+        (self.julian_date_terrestial_time() - self.epoch) / self.body_host_ratio()
+            + self.offset / 24.0
+    }
+
+    fn coordinated_time(&self) -> f64 {
+        (24.0 * self.day_date()) % 24.0
+    }
+
+    fn fractional_hour(&self) -> f64 {
+        self.day_date().rem_euclid(1.0)
+    }
+
+    fn fractional_minute(&self) -> f64 {
+        (24.0 * self.fractional_hour()).fract()
+    }
+
+    fn now(&self) -> Time {
+        let hour = (24.0 * self.fractional_hour()).floor();
+        let minute = (60.0 * self.fractional_minute()).floor();
+        let second = 60.0 * (60.0 * self.fractional_minute()).fract();
+
+        Time {
+            hour: hour as i32,
+            minute: minute as u8,
+            second: second as u8,
+            code: self.code.clone(),
+            name: self.name.clone(),
+            offset_name: format!("{}{:+}", self.body, self.offset),
+            hour_type: HourType::new(&HourType::Unknown, hour as u8),
+        }
+    }
+}
+
+fn solar() -> f64 {
+    1.0
+}
+
+fn seasons() -> Vec<String> {
+    ["Spring", "Summer", "Autumn", "Winter"]
+        .map(String::from)
+        .to_vec()
+}
@@ -18,6 +18,23 @@ pub fn au2km(value: f64) -> f64 {
     value * (1.495_978_707 * 100_000_000_000.0)
 }
 
+/// The astronomical unit in kilometers
+///
+/// > Numbers are according to <https://en.wikipedia.org/wiki/Astronomical_unit>
+pub const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+
+/// The speed of light in kilometers per second
+pub const SPEED_OF_LIGHT: f64 = 299_792.458;
+
+/// The gravitational parameter (GM) of the sun in cubic kilometers per second squared
+pub const SOLAR_GRAVITATIONAL_PARAMETER: f64 = 1.327_124_400_18e11;
+
+/// The total solar irradiance at 1 AU in watts per square meter
+pub const SOLAR_CONSTANT: f64 = 1361.0;
+
+/// The gravitational constant in cubic kilometers per kilogram per second squared
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-20;
+
 /// This function is a wrapper over calculating the radians in a circle
 ///
 pub fn radians_in_circle() -> f64 {
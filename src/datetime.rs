@@ -0,0 +1,428 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+use chrono::Duration;
+
+use crate::{
+    julian::JD2NOON,
+    kepler::{Body, Date},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The nanoseconds in a second
+const NANOS: i64 = 1_000_000_000;
+
+/// The times the search for a solar longitude is refined
+const ITERATIONS: usize = 50;
+
+/// The degrees of solar longitude the search stops within
+const TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// This structure is a count of local days, the solar days of a body.
+pub struct Sols(pub i64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// This is the collection of steps a [`DateRange`] can take through a body's calendar.
+pub enum Step {
+    /// Steps by a number of local days
+    Sols(u32),
+    /// Steps by a number of months, the solar longitude of a month apart
+    Months(u32),
+    /// Steps by a number of calendar years
+    Years(u32),
+    /// Steps by degrees of solar longitude (Ls)
+    SolarLongitude(f64),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// This structure is the time between two planetary date times.
+pub struct Span {
+    /// ### The earth seconds between the date times, held at the longest [`Duration`] when further apart
+    pub duration: Duration,
+    /// ### The local days (solar days of the body) between the date times
+    pub sols: f64,
+}
+
+impl Span {
+    /// This method gets the earth seconds between the date times, with the fraction.
+    pub fn seconds(&self) -> f64 {
+        self.duration.num_seconds() as f64 + self.duration.subsec_nanos() as f64 / NANOS as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// This structure is an instant in the calendar of a body, like [`chrono::DateTime`] is for earth.
+///
+/// The instant is kept to the nanosecond since J2000 (TT), so adding and subtracting is exact.
+/// Instants stop at the first and last second an `i64` can count rather than overflow.
+/// Date times are compared, ordered and hashed by their instant alone.
+///
+/// ```rust
+/// use chrono::Duration;
+/// use rust_solar::datetime::{PlanetaryDateTime, Sols};
+/// use rust_solar::planets::mars::Mars;
+///
+/// // perseverance landed on 2021-02-18 at 20:55 UTC
+/// let landing = PlanetaryDateTime::new(Mars, 2459264.37233);
+/// let later = landing + Sols(100) + Duration::hours(1);
+/// let span = later - landing;
+///
+/// assert_eq!(8_877_524 + 3600, span.duration.num_seconds());
+/// assert!((span.sols - 100.04).abs() < 0.01);
+/// assert!(landing < later);
+/// ```
+pub struct PlanetaryDateTime<B: Body> {
+    /// ### The body whose calendar the instant is in
+    pub body: B,
+    seconds: i64,
+    nanos: u32,
+}
+
+impl<B: Body> PlanetaryDateTime<B> {
+    /// This method creates the instant of a julian date (TT) on a body.
+    ///
+    /// This method panics on a julian date that is not finite or too far to count, see [`Self::try_new`].
+    pub fn new(body: B, julian_date: f64) -> Self {
+        match Self::try_new(body, julian_date) {
+            Some(instant) => instant,
+            None => panic!("the julian date {julian_date} is out of range"),
+        }
+    }
+
+    /// This method creates the instant of a julian date (TT) on a body, or none when it is not finite
+    /// or its seconds from J2000 do not fit an `i64`.
+    pub fn try_new(body: B, julian_date: f64) -> Option<Self> {
+        let seconds = (julian_date - JD2NOON) * EARTH_ROTATIONAL_PERIOD;
+        let whole = seconds.floor();
+
+        // an i64 reaches up to, not including, 2^63
+        if !(whole.is_finite() && whole >= i64::MIN as f64 && whole < i64::MAX as f64) {
+            return None;
+        }
+
+        Some(Self::from_parts(
+            body,
+            whole as i64,
+            ((seconds - whole) * NANOS as f64).round() as i64,
+        ))
+    }
+
+    /// This method creates the instant at the start of a date in the body's calendar.
+    pub fn from_date(body: B, date: &Date) -> Self {
+        let julian_date = body.julian_date_of(date);
+
+        Self::new(body, julian_date)
+    }
+
+    /// This method keeps the nanoseconds within a second.
+    fn from_parts(body: B, seconds: i64, nanos: i64) -> Self {
+        Self {
+            body,
+            seconds: seconds + nanos.div_euclid(NANOS),
+            nanos: nanos.rem_euclid(NANOS) as u32,
+        }
+    }
+
+    /// This method creates the instant a number of nanoseconds since J2000 (TT) on a body.
+    pub fn from_nanoseconds(body: B, nanoseconds: i128) -> Self {
+        let (seconds, nanos) = Self::split(nanoseconds);
+
+        Self {
+            body,
+            seconds,
+            nanos,
+        }
+    }
+
+    /// This method splits nanoseconds into seconds and the nanoseconds within a second, held at the
+    /// first and last second an `i64` counts.
+    fn split(nanoseconds: i128) -> (i64, u32) {
+        let nanos = NANOS as i128;
+        let nanoseconds = nanoseconds.clamp(
+            i64::MIN as i128 * nanos,
+            i64::MAX as i128 * nanos + nanos - 1,
+        );
+
+        (
+            nanoseconds.div_euclid(nanos) as i64,
+            nanoseconds.rem_euclid(nanos) as u32,
+        )
+    }
+
+    /// This method gets the nanoseconds since J2000 (TT) of the instant.
+    pub fn nanoseconds(&self) -> i128 {
+        self.seconds as i128 * NANOS as i128 + self.nanos as i128
+    }
+
+    /// This method gets the julian date (TT) of the instant.
+    pub fn julian_date(&self) -> f64 {
+        JD2NOON + (self.seconds as f64 + self.nanos as f64 / NANOS as f64) / EARTH_ROTATIONAL_PERIOD
+    }
+
+    /// This method computes the date of the instant in the body's calendar.
+    pub fn date(&self) -> Date
+    where
+        B: Clone,
+    {
+        self.body.clone().to_date(self.julian_date())
+    }
+
+    /// This method steps forward through the body's calendar.
+    pub fn advance(self, step: Step) -> Self
+    where
+        B: Clone,
+    {
+        match step {
+            Step::Sols(sols) => self + Sols(sols.into()),
+            Step::Months(months) => {
+                let degrees = months as f64 * self.body.perihelion().avg_ls();
+                self.solar_longitude_after(degrees)
+            }
+            Step::Years(years) => {
+                let year = self.body.orbital_period() * self.body.rotational_period();
+                let nanoseconds = self.nanoseconds() + Self::periods(year, years.into());
+                Self::from_nanoseconds(self.body, nanoseconds)
+            }
+            Step::SolarLongitude(degrees) => self.solar_longitude_after(degrees),
+        }
+    }
+
+    /// This method finds the first instant from this one that the solar longitude (Ls) is at degrees.
+    ///
+    /// ```rust
+    /// use rust_solar::datetime::PlanetaryDateTime;
+    /// use rust_solar::planets::mars::Mars;
+    ///
+    /// // the northern spring equinox that starts mars year 37
+    /// let equinox = PlanetaryDateTime::new(Mars, 2459640.0).next_solar_longitude(0.0);
+    ///
+    /// assert!(equinox.date().ls < 1e-6 || equinox.date().ls > 360.0 - 1e-6);
+    /// ```
+    pub fn next_solar_longitude(self, degrees: f64) -> Self
+    where
+        B: Clone,
+    {
+        let ahead = (degrees - self.date().ls).rem_euclid(360.0);
+
+        self.solar_longitude_after(ahead)
+    }
+
+    /// This method finds the instant the month of this one started, when the solar longitude entered it.
+    pub fn month_start(self) -> Self
+    where
+        B: Clone,
+    {
+        let date = self.date();
+        let into = date.ls - (date.month as f64 - 1.0) * self.body.perihelion().avg_ls();
+
+        self.solar_longitude_after(-into)
+    }
+
+    /// This method finds the instant the solar longitude (Ls) has moved degrees from this one.
+    fn solar_longitude_after(self, degrees: f64) -> Self
+    where
+        B: Clone,
+    {
+        let mut body = self.body.clone();
+        let year = body.orbital_period() * body.rotational_period() / EARTH_ROTATIONAL_PERIOD;
+        let target = (self.date().ls + degrees).rem_euclid(360.0);
+
+        // a mean motion guess, then the sun's true motion pulls it in
+        let mut julian_date = self.julian_date() + degrees / 360.0 * year;
+        for _ in 0..ITERATIONS {
+            let behind = (target - body.to_date(julian_date).ls + 180.0).rem_euclid(360.0) - 180.0;
+            if behind.abs() < TOLERANCE {
+                break;
+            }
+
+            julian_date += behind / 360.0 * year;
+        }
+
+        Self::new(body, julian_date)
+    }
+
+    /// This method iterates from this instant up to, not including, the end.
+    ///
+    /// ```rust
+    /// use rust_solar::datetime::{PlanetaryDateTime, Step};
+    /// use rust_solar::planets::mars::Mars;
+    ///
+    /// let start = PlanetaryDateTime::new(Mars, 2459640.0);
+    /// let sols = start.range(start.advance(Step::Sols(30)), Step::Sols(1));
+    ///
+    /// assert_eq!(30, sols.count());
+    /// ```
+    pub fn range(self, end: Self, step: Step) -> DateRange<B>
+    where
+        B: Clone,
+    {
+        DateRange {
+            next: self,
+            end,
+            step,
+        }
+    }
+
+    /// This method gets the nanoseconds of a number of periods, each rounded to the nanosecond so they add up the same.
+    fn periods(seconds: f64, times: i64) -> i128 {
+        // a period of more than 2^63 nanoseconds is 292 earth years, longer than any sol or year here
+        let nanos = (seconds * NANOS as f64).round() as i64;
+
+        nanos as i128 * times as i128
+    }
+}
+
+impl<B: Body> Add<Duration> for PlanetaryDateTime<B> {
+    type Output = Self;
+
+    fn add(mut self, duration: Duration) -> Self {
+        self += duration;
+        self
+    }
+}
+
+impl<B: Body> Sub<Duration> for PlanetaryDateTime<B> {
+    type Output = Self;
+
+    fn sub(mut self, duration: Duration) -> Self {
+        self -= duration;
+        self
+    }
+}
+
+impl<B: Body> Add<Sols> for PlanetaryDateTime<B> {
+    type Output = Self;
+
+    fn add(mut self, sols: Sols) -> Self {
+        self += sols;
+        self
+    }
+}
+
+impl<B: Body> Sub<Sols> for PlanetaryDateTime<B> {
+    type Output = Self;
+
+    fn sub(mut self, sols: Sols) -> Self {
+        self -= sols;
+        self
+    }
+}
+
+impl<B: Body> AddAssign<Duration> for PlanetaryDateTime<B> {
+    fn add_assign(&mut self, duration: Duration) {
+        let nanoseconds =
+            duration.num_seconds() as i128 * NANOS as i128 + duration.subsec_nanos() as i128;
+
+        (self.seconds, self.nanos) = Self::split(self.nanoseconds() + nanoseconds);
+    }
+}
+
+impl<B: Body> SubAssign<Duration> for PlanetaryDateTime<B> {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self += -duration;
+    }
+}
+
+impl<B: Body> AddAssign<Sols> for PlanetaryDateTime<B> {
+    fn add_assign(&mut self, sols: Sols) {
+        let nanoseconds = Self::periods(self.body.rotational_period(), sols.0);
+
+        (self.seconds, self.nanos) = Self::split(self.nanoseconds() + nanoseconds);
+    }
+}
+
+impl<B: Body> SubAssign<Sols> for PlanetaryDateTime<B> {
+    fn sub_assign(&mut self, sols: Sols) {
+        let nanoseconds = Self::periods(self.body.rotational_period(), sols.0);
+
+        (self.seconds, self.nanos) = Self::split(self.nanoseconds() - nanoseconds);
+    }
+}
+
+impl<B: Body> Sub for PlanetaryDateTime<B> {
+    type Output = Span;
+
+    fn sub(self, other: Self) -> Span {
+        let nanos = NANOS as i128;
+        let (longest, shortest) = (Duration::max_value(), Duration::min_value());
+        let nanoseconds = (self.nanoseconds() - other.nanoseconds()).clamp(
+            shortest.num_seconds() as i128 * nanos + shortest.subsec_nanos() as i128,
+            longest.num_seconds() as i128 * nanos + longest.subsec_nanos() as i128,
+        );
+
+        // the seconds and their rest share a sign, so neither part passes the bounds on its own
+        Span {
+            duration: Duration::seconds((nanoseconds / nanos) as i64)
+                + Duration::nanoseconds((nanoseconds % nanos) as i64),
+            sols: (self.nanoseconds() - other.nanoseconds()) as f64
+                / nanos as f64
+                / self.body.rotational_period(),
+        }
+    }
+}
+
+impl<B: Body> PartialEq for PlanetaryDateTime<B> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.seconds, self.nanos) == (other.seconds, other.nanos)
+    }
+}
+
+impl<B: Body> Eq for PlanetaryDateTime<B> {}
+
+impl<B: Body> PartialOrd for PlanetaryDateTime<B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<B: Body> Ord for PlanetaryDateTime<B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.seconds, self.nanos).cmp(&(other.seconds, other.nanos))
+    }
+}
+
+impl<B: Body> Hash for PlanetaryDateTime<B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.seconds, self.nanos).hash(state);
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This structure iterates through a body's calendar, from a date time up to another.
+///
+/// Steps that would not move forward end the range.
+pub struct DateRange<B: Body + Clone> {
+    next: PlanetaryDateTime<B>,
+    end: PlanetaryDateTime<B>,
+    step: Step,
+}
+
+impl<B: Body + Clone> DateRange<B> {
+    /// This method iterates through the dates of the body's calendar instead.
+    pub fn dates(self) -> impl Iterator<Item = Date> {
+        self.map(|time| time.date())
+    }
+}
+
+impl<B: Body + Clone> Iterator for DateRange<B> {
+    type Item = PlanetaryDateTime<B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let current = self.next.clone();
+        let next = current.clone().advance(self.step);
+        self.next = match next > current {
+            true => next,
+            false => self.end.clone(),
+        };
+
+        Some(current)
+    }
+}
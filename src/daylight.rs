@@ -0,0 +1,224 @@
+use strum::AsRefStr;
+
+use crate::{
+    conversions::ASTRONOMICAL_UNIT,
+    kepler::{Body, Clock, Date, Time},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The times the noon, sunrise and sunset are recalculated for the sun's movement
+const ITERATIONS: usize = 4;
+
+/// The angular radius of the sun in degrees at 1 AU
+const SOLAR_SEMIDIAMETER: f64 = 0.2666;
+
+/// The degrees below the horizon where civil twilight ends
+const TWILIGHT: f64 = 6.0;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, AsRefStr)]
+/// This is the collection of days where the sun does not rise or set
+pub enum Polar {
+    /// The sun rises and sets
+    #[default]
+    #[strum(serialize = "Neither")]
+    Neither,
+    /// The sun stays above the horizon all day
+    #[strum(serialize = "Polar Day")]
+    Day,
+    /// The sun stays below the horizon all day
+    #[strum(serialize = "Polar Night")]
+    Night,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains the sun's day at a place on a body.
+///
+/// The times are julian dates so they can be read on any clock with [`Daylight::local`],
+/// like a [`Sundial`] of the body or a [`crate::planets::mars::Martian`] zone.
+///
+/// ```rust
+/// use rust_solar::daylight::{Daylight, Polar, Sundial};
+/// use rust_solar::kepler::Body;
+/// use rust_solar::planets::mars::Mars;
+///
+/// // Jezero crater (18.44°N, 77.45°E)
+/// let date = Mars.to_date(2459630.5);
+/// let day = Daylight::default().compute(&Mars, &date, 18.44, 77.45);
+///
+/// assert_eq!(Polar::Neither, day.polar);
+/// assert!(day.day_length > 10.0 && day.day_length < 14.0);
+///
+/// // a sundial at the crater reads noon at 12:00
+/// let sundial = Sundial { body: &Mars, longitude: 77.45, mean: false };
+/// assert_eq!((12, 0), (day.local(&sundial).noon.hour, day.local(&sundial).noon.minute));
+/// ```
+pub struct Daylight {
+    /// ### The julian date the sun crosses the meridian
+    pub noon: f64,
+    /// ### The julian date the top of the sun rises, `None` on polar days and nights
+    pub sunrise: Option<f64>,
+    /// ### The julian date the top of the sun sets, `None` on polar days and nights
+    pub sunset: Option<f64>,
+    /// ### The julian date civil twilight starts, `None` when it lasts all night or never starts
+    pub dawn: Option<f64>,
+    /// ### The julian date civil twilight ends, `None` when it lasts all night or never ends
+    pub dusk: Option<f64>,
+    /// ### The hours (of the body's 24 hour day) the sun is up
+    pub day_length: f64,
+    /// ### Whether the sun stays up or down all day
+    pub polar: Polar,
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains the times of a [`Daylight`] read on a clock.
+pub struct LocalDaylight {
+    /// ### The time the sun crosses the meridian
+    pub noon: Time,
+    /// ### The time the top of the sun rises, `None` on polar days and nights
+    pub sunrise: Option<Time>,
+    /// ### The time the top of the sun sets, `None` on polar days and nights
+    pub sunset: Option<Time>,
+    /// ### The time civil twilight starts, `None` when it lasts all night or never starts
+    pub dawn: Option<Time>,
+    /// ### The time civil twilight ends, `None` when it lasts all night or never ends
+    pub dusk: Option<Time>,
+}
+
+#[derive(Clone, Copy)]
+/// This data structure is the solar clock of an east longitude (degrees) on any body.
+///
+/// It keeps the local true solar time (LTST) a sundial shows,
+/// or the local mean solar time (LMST) when `mean` is set.
+pub struct Sundial<'a> {
+    /// ### The body the sundial stands on
+    pub body: &'a dyn Body,
+    /// ### The east longitude in degrees of the sundial
+    pub longitude: f64,
+    /// ### Whether the clock keeps the mean solar time rather than the true one
+    pub mean: bool,
+}
+
+impl std::fmt::Debug for Sundial<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sundial")
+            .field("body", &self.body.name())
+            .field("longitude", &self.longitude)
+            .field("mean", &self.mean)
+            .finish()
+    }
+}
+
+impl Clock for Sundial<'_> {
+    fn day_length(&self) -> f64 {
+        self.body.rotational_period() / EARTH_ROTATIONAL_PERIOD
+    }
+
+    fn hours(&self, julian_date: f64) -> f64 {
+        match self.mean {
+            true => self.body.mean_solar_time(julian_date, self.longitude),
+            false => self.body.solar_time(julian_date, self.longitude),
+        }
+    }
+
+    fn labels(&self, _julian_date: f64) -> (String, String, String) {
+        let (code, name) = match self.mean {
+            true => ("LMST", "Local Mean Solar Time"),
+            false => ("LTST", "Local True Solar Time"),
+        };
+
+        (
+            code.to_string(),
+            name.to_string(),
+            format!("{}°E", self.longitude),
+        )
+    }
+}
+
+impl Daylight {
+    /// This method reads the times of the day on a clock, like a [`Sundial`] or a time zone of the body.
+    pub fn local(&self, clock: &dyn Clock) -> LocalDaylight {
+        let read =
+            |julian_date: Option<f64>| julian_date.map(|julian_date| clock.time(julian_date));
+
+        LocalDaylight {
+            noon: clock.time(self.noon),
+            sunrise: read(self.sunrise),
+            sunset: read(self.sunset),
+            dawn: read(self.dawn),
+            dusk: read(self.dusk),
+        }
+    }
+
+    /// This method computes the day at a latitude and east longitude (degrees) on a date of the body.
+    pub fn compute(&self, body: &dyn Body, date: &Date, latitude: f64, longitude: f64) -> Self {
+        let day = body.rotational_period() / EARTH_ROTATIONAL_PERIOD;
+
+        // the first noon after the date starts
+        let start = body.julian_date_of(date);
+        let mut noon =
+            start + (12.0 - body.solar_time(start, longitude)).rem_euclid(24.0) / 24.0 * day;
+        for _ in 0..ITERATIONS {
+            noon -= (body.solar_time(noon, longitude) - 12.0) / 24.0 * day;
+        }
+
+        let semidiameter = SOLAR_SEMIDIAMETER * ASTRONOMICAL_UNIT
+            / body
+                .heliocentric_state_at(noon)
+                .distance
+                .max(f64::MIN_POSITIVE);
+
+        let hour_angle = |julian_date: f64, altitude: f64| {
+            let (declination, _) = body.subsolar_point(julian_date);
+            let (latitude, declination) = (latitude.to_radians(), declination.to_radians());
+
+            (altitude.to_radians().sin() - latitude.sin() * declination.sin())
+                / (latitude.cos() * declination.cos())
+        };
+
+        // the sun moves in declination during the day, so each crossing is refined on its own
+        let crossing = |altitude: f64, rising: bool| {
+            let mut julian_date = noon;
+
+            for _ in 0..ITERATIONS {
+                let cosine = hour_angle(julian_date, altitude);
+                if cosine.abs() > 1.0 {
+                    return Err(cosine);
+                }
+
+                let offset = cosine.acos().to_degrees() / 360.0 * day;
+                julian_date = match rising {
+                    true => noon - offset,
+                    false => noon + offset,
+                };
+            }
+
+            Ok(julian_date)
+        };
+
+        let (sunrise, sunset) = (
+            crossing(-semidiameter, true),
+            crossing(-semidiameter, false),
+        );
+        let polar = match (sunrise, sunset) {
+            (Err(cosine), _) | (_, Err(cosine)) if cosine < -1.0 => Polar::Day,
+            (Err(_), _) | (_, Err(_)) => Polar::Night,
+            _ => Polar::Neither,
+        };
+
+        let day_length = match (sunrise, sunset, polar) {
+            (Ok(rise), Ok(set), _) => (set - rise) / day * 24.0,
+            (_, _, Polar::Day) => 24.0,
+            _ => 0.0,
+        };
+
+        Self {
+            noon,
+            sunrise: sunrise.ok(),
+            sunset: sunset.ok(),
+            dawn: crossing(-TWILIGHT, true).ok(),
+            dusk: crossing(-TWILIGHT, false).ok(),
+            day_length,
+            polar,
+        }
+    }
+}
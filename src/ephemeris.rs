@@ -0,0 +1,342 @@
+use strum::{AsRefStr, VariantArray};
+
+use crate::{
+    julian::JD2NOON,
+    kepler::{Body, Date},
+    orbit::{add, norm, scale, Perihelion, StateVector},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The days in a julian century
+pub const JULIAN_CENTURY: f64 = 36525.0;
+
+/// This trait acts as a common field for anything that knows where a body is.
+///
+/// The state is heliocentric, in the ecliptic J2000 frame, in kilometers and kilometers per second.
+pub trait Ephemeris {
+    /// This method gets the state of the body at a julian date (TT)
+    fn state_at(&self, julian_date: f64) -> StateVector;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, AsRefStr, VariantArray)]
+/// This is the collection of planets with analytic mean elements
+///
+/// The elements and their rates per century are from
+/// [Approximate Positions of the Planets](https://ssd.jpl.nasa.gov/planets/approx_pos.html) (E.M. Standish),
+/// the same form as Meeus' *Astronomical Algorithms* chapter 31, referred to the ecliptic J2000.
+///
+/// ## Limitations
+/// `1800 to 2050`: Mercury to Mars stay within arcminutes, the giant planets drift by up to a degree.
+/// These are mean elements, not a planetary theory, use [`Vsop87`](crate::vsop87::Vsop87) for arcsecond positions.
+///
+pub enum Planetary {
+    /// The first planet from the sun
+    Mercury,
+    /// The second planet from the sun
+    Venus,
+    /// The earth-moon barycenter
+    Earth,
+    /// The fourth planet from the sun
+    #[default]
+    Mars,
+    /// The fifth planet from the sun
+    Jupiter,
+    /// The sixth planet from the sun
+    Saturn,
+    /// The seventh planet from the sun
+    Uranus,
+    /// The eighth planet from the sun
+    Neptune,
+}
+
+impl Planetary {
+    /// The elements at J2000 and their rate per julian century
+    ///
+    /// ### (a, e, i, L, ϖ, Ω) in AU and degrees
+    pub fn elements(&self) -> [(f64, f64); 6] {
+        match self {
+            Self::Mercury => [
+                (0.38709927, 0.00000037),
+                (0.20563593, 0.00001906),
+                (7.00497902, -0.00594749),
+                (252.25032350, 149472.67411175),
+                (77.45779628, 0.16047689),
+                (48.33076593, -0.12534081),
+            ],
+            Self::Venus => [
+                (0.72333566, 0.00000390),
+                (0.00677672, -0.00004107),
+                (3.39467605, -0.00078890),
+                (181.97909950, 58517.81538729),
+                (131.60246718, 0.00268329),
+                (76.67984255, -0.27769418),
+            ],
+            Self::Earth => [
+                (1.00000261, 0.00000562),
+                (0.01671123, -0.00004392),
+                (-0.00001531, -0.01294668),
+                (100.46457166, 35999.37244981),
+                (102.93768193, 0.32327364),
+                (0.0, 0.0),
+            ],
+            Self::Mars => [
+                (1.52371034, 0.00001847),
+                (0.09339410, 0.00007882),
+                (1.84969142, -0.00813131),
+                (-4.55343205, 19140.30268499),
+                (-23.94362959, 0.44441088),
+                (49.55953891, -0.29257343),
+            ],
+            Self::Jupiter => [
+                (5.20288700, -0.00011607),
+                (0.04838624, -0.00013253),
+                (1.30439695, -0.00183714),
+                (34.39644051, 3034.74612775),
+                (14.72847983, 0.21252668),
+                (100.47390909, 0.20469106),
+            ],
+            Self::Saturn => [
+                (9.53667594, -0.00125060),
+                (0.05386179, -0.00050991),
+                (2.48599187, 0.00193609),
+                (49.95424423, 1222.49362201),
+                (92.59887831, -0.41897216),
+                (113.66242448, -0.28867794),
+            ],
+            Self::Uranus => [
+                (19.18916464, -0.00196176),
+                (0.04725744, -0.00004397),
+                (0.77263783, -0.00242939),
+                (313.23810451, 428.48202785),
+                (170.95427630, 0.40805281),
+                (74.01692503, 0.04240589),
+            ],
+            Self::Neptune => [
+                (30.06992276, 0.00026291),
+                (0.00859048, 0.00005105),
+                (1.77004347, 0.00035372),
+                (-55.12002969, 218.45945325),
+                (44.96476227, -0.32241464),
+                (131.78422574, -0.00508664),
+            ],
+        }
+    }
+}
+
+impl Ephemeris for Planetary {
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        let centuries = (julian_date - JD2NOON) / JULIAN_CENTURY;
+        let [a, e, i, l, peri, node] = self
+            .elements()
+            .map(|(value, rate)| value + rate * centuries);
+
+        // M = L - ϖ, ω = ϖ - Ω
+        let mean = (l - peri).rem_euclid(360.0).to_radians();
+        let mut eccentric = mean + e * mean.sin();
+
+        // Newtons Iterative step
+        let mut edx: f64 = 10.0;
+        while edx.abs() > 1.0e-12 {
+            edx = (mean - eccentric + e * eccentric.sin()) / (1.0 - e * eccentric.cos());
+            eccentric += edx;
+        }
+
+        let true_anomaly = 2.0
+            * ((1.0 + e).sqrt() * (eccentric / 2.0).sin())
+                .atan2((1.0 - e).sqrt() * (eccentric / 2.0).cos());
+
+        // the period follows the rate of the mean longitude
+        let orbital_period =
+            360.0 / self.elements()[3].1 * JULIAN_CENTURY * EARTH_ROTATIONAL_PERIOD;
+
+        StateVector::default().compute(true_anomaly, e, a, orbital_period, (i, node, peri - node))
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains states at julian dates, interpolated in between.
+///
+/// Uses cubic hermite interpolation of the positions and velocities,
+/// and extrapolates from the first or last pair outside of the table.
+pub struct Tabulated {
+    /// ### (Julian Date, State) ordered by julian date
+    pub rows: Vec<(f64, StateVector)>,
+}
+
+impl Ephemeris for Tabulated {
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        if self.rows.len() < 2 {
+            return self.rows.first().map(|row| row.1).unwrap_or_default();
+        }
+
+        let index = self
+            .rows
+            .partition_point(|row| row.0 <= julian_date)
+            .clamp(1, self.rows.len() - 1);
+        let ((start, first), (end, last)) = (self.rows[index - 1], self.rows[index]);
+
+        let h = (end - start) * EARTH_ROTATIONAL_PERIOD;
+        let t = (julian_date - start) / (end - start);
+        let (t2, t3) = (t * t, t * t * t);
+
+        // hermite basis functions and their derivatives
+        let basis = [
+            2.0 * t3 - 3.0 * t2 + 1.0,
+            t3 - 2.0 * t2 + t,
+            3.0 * t2 - 2.0 * t3,
+            t3 - t2,
+        ];
+        let rates = [
+            6.0 * t2 - 6.0 * t,
+            3.0 * t2 - 4.0 * t + 1.0,
+            6.0 * t - 6.0 * t2,
+            3.0 * t2 - 2.0 * t,
+        ];
+
+        let blend = |weights: [f64; 4]| {
+            add(
+                add(
+                    scale(first.position, weights[0]),
+                    scale(first.velocity, h * weights[1]),
+                ),
+                add(
+                    scale(last.position, weights[2]),
+                    scale(last.velocity, h * weights[3]),
+                ),
+            )
+        };
+        let position = blend(basis);
+
+        StateVector {
+            position,
+            velocity: scale(blend(rates), 1.0 / h),
+            distance: norm(position),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// This structure swaps the two-body orbit of a body for an ephemeris.
+///
+/// The calendar still counts years and days the same way,
+/// but the position, distance and solar longitude come from the ephemeris.
+///
+/// ```rust
+/// use rust_solar::ephemeris::{EphemerisBody, Planetary};
+/// use rust_solar::kepler::Body;
+/// use rust_solar::planets::mars::Mars;
+///
+/// let mut mars = EphemerisBody { body: Mars, ephemeris: Planetary::Mars };
+///
+/// // Mars24 gives an Ls of 277.18758° for 2000-01-06 00:00 UTC
+/// assert!((mars.to_date(2451549.50074).ls - 277.18758).abs() < 0.05);
+/// ```
+pub struct EphemerisBody<B: Body, E: Ephemeris> {
+    /// This is the body that keeps its calendar
+    pub body: B,
+    /// This is the ephemeris that places the body
+    pub ephemeris: E,
+}
+
+impl<B: Body, E: Ephemeris> Body for EphemerisBody<B, E> {
+    fn epoch(&self) -> f64 {
+        self.body.epoch()
+    }
+
+    fn name(&self) -> String {
+        self.body.name()
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        self.body.orbital_eccentricity()
+    }
+
+    fn orbital_period(&self) -> f64 {
+        self.body.orbital_period()
+    }
+
+    fn rotational_period(&self) -> f64 {
+        self.body.rotational_period()
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        self.body.perihelion()
+    }
+
+    fn semimajor(&self) -> f64 {
+        self.body.semimajor()
+    }
+
+    fn inclination(&self) -> f64 {
+        self.body.inclination()
+    }
+
+    fn ascending_node(&self) -> f64 {
+        self.body.ascending_node()
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        self.body.argument_of_perihelion()
+    }
+
+    fn obliquity(&self) -> f64 {
+        self.body.obliquity()
+    }
+
+    fn luminosity(&self) -> f64 {
+        self.body.luminosity()
+    }
+
+    fn sidereal_period(&self) -> f64 {
+        self.body.sidereal_period()
+    }
+
+    fn prime_meridian(&self) -> f64 {
+        self.body.prime_meridian()
+    }
+
+    fn pole(&self) -> (f64, f64) {
+        self.body.pole()
+    }
+
+    fn pole_precession(&self) -> (f64, f64) {
+        self.body.pole_precession()
+    }
+
+    fn host(&self) -> Option<&'static dyn Body> {
+        self.body.host()
+    }
+
+    fn oblateness(&self) -> f64 {
+        self.body.oblateness()
+    }
+
+    fn equatorial_radius(&self) -> f64 {
+        self.body.equatorial_radius()
+    }
+
+    fn precession(&self) -> (f64, f64) {
+        self.body.precession()
+    }
+
+    fn perihelion_precession(&self) -> f64 {
+        self.body.perihelion_precession()
+    }
+
+    fn solar_longitude(&self, julian_date: f64) -> f64 {
+        self.state_at(julian_date)
+            .solar_longitude(self.pole_at(julian_date))
+    }
+
+    fn to_date(&mut self, julian_date: f64) -> Date {
+        let ls = self.solar_longitude(julian_date);
+
+        self.body
+            .to_date(julian_date)
+            .with_solar_longitude(ls, self.perihelion())
+    }
+
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        self.ephemeris.state_at(julian_date)
+    }
+}
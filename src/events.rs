@@ -0,0 +1,233 @@
+use displaydoc::Display;
+use strum::AsRefStr;
+use thiserror::Error;
+
+use crate::{
+    kepler::{Body, Date},
+    orbit::StateVector,
+    planets::earth::Earth,
+};
+
+/// The bisections it takes to narrow an event down to under a minute
+const REFINEMENTS: usize = 24;
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Error)]
+/// This is the collection of problems with the dates of a search
+pub enum EventError {
+    /// the step of {0} days must be a positive number
+    Step(f64),
+    /// the dates from {0} to {1} must be finite
+    Bounds(f64, f64),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, AsRefStr)]
+/// This is the collection of events a body goes through as seen from earth
+pub enum Kind {
+    /// The body is opposite of the sun in the sky
+    #[strum(serialize = "Opposition")]
+    Opposition,
+    /// The body is behind the sun
+    #[strum(serialize = "Superior Conjunction")]
+    SuperiorConjunction,
+    /// The body is between the earth and the sun
+    #[strum(serialize = "Inferior Conjunction")]
+    InferiorConjunction,
+    /// The body is too close to the sun in the sky to communicate with
+    #[strum(serialize = "Solar Conjunction Blackout")]
+    Blackout,
+    /// The base case of an event
+    #[default]
+    #[strum(serialize = "Unknown")]
+    Unknown,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains when an event happens
+pub struct Event {
+    /// ### The kind of event
+    pub kind: Kind,
+    /// ### (Start, End) in julian dates, the same date for instant events
+    pub interval: (f64, f64),
+}
+
+impl Event {
+    /// This method converts the interval into the body's own dates.
+    pub fn dates(&self, body: &mut dyn Body) -> (Date, Date) {
+        (body.to_date(self.interval.0), body.to_date(self.interval.1))
+    }
+
+    /// The length of the event in earth days
+    pub fn duration(&self) -> f64 {
+        self.interval.1 - self.interval.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This structure searches for conjunctions, oppositions and blackouts as seen from earth.
+pub struct Events;
+
+impl Events {
+    /// This method computes the sun-earth-body angle (elongation) in degrees.
+    pub fn elongation(&self, body: &dyn Body, julian_date: f64) -> f64 {
+        let (earth, target) = Self::states(body, julian_date);
+        let (ex, ey, ez) = earth.position;
+        let (bx, by, bz) = target.position;
+
+        // earth -> sun is -earth, earth -> body is body - earth
+        let (dx, dy, dz) = (bx - ex, by - ey, bz - ez);
+        let dot = -(ex * dx + ey * dy + ez * dz);
+        let norm = earth.distance * (dx * dx + dy * dy + dz * dz).sqrt();
+
+        (dot / norm).clamp(-1.0, 1.0).acos().to_degrees()
+    }
+
+    /// This method finds the oppositions and conjunctions from `start` to `end`.
+    ///
+    /// The `step` (earth days) must be positive and shorter than half the synodic period.
+    pub fn search(
+        &self,
+        body: &dyn Body,
+        start: f64,
+        end: f64,
+        step: f64,
+    ) -> Result<Vec<Event>, EventError> {
+        let mut list = Vec::new();
+
+        for (julian_date, next) in Self::steps(start, end, step)? {
+            for target in [0.0, 180.0] {
+                let angle = |jd: f64| Self::separation(body, jd, target);
+                let (before, after) = (angle(julian_date), angle(next));
+
+                // the sign flips on crossing, while the wrap around jumps by a whole turn
+                if before.signum() != after.signum() && (after - before).abs() < 180.0 {
+                    let instant = Self::refine(julian_date, next, |jd| angle(jd) < 0.0);
+                    let kind = match target == 0.0 {
+                        true => Self::conjunction(body, instant),
+                        false => Kind::Opposition,
+                    };
+
+                    list.push(Event {
+                        kind,
+                        interval: (instant, instant),
+                    });
+                }
+            }
+        }
+
+        Ok(list)
+    }
+
+    /// This method finds when the elongation is below the `threshold` (degrees) from `start` to `end`.
+    ///
+    /// Mars missions stop commanding within ~2-3° of the sun, as the solar plasma garbles the signal.
+    pub fn blackouts(
+        &self,
+        body: &dyn Body,
+        start: f64,
+        end: f64,
+        step: f64,
+        threshold: f64,
+    ) -> Result<Vec<Event>, EventError> {
+        let hidden = |jd: f64| self.elongation(body, jd) < threshold;
+        let steps = Self::steps(start, end, step)?;
+
+        let mut list = Vec::new();
+        let mut opened = match hidden(start) {
+            true => Some(start),
+            false => None,
+        };
+
+        for (julian_date, next) in steps {
+            match (opened, hidden(next)) {
+                (None, true) => opened = Some(Self::refine(julian_date, next, hidden)),
+                (Some(open), false) => {
+                    let close = Self::refine(julian_date, next, |jd| !hidden(jd));
+
+                    list.push(Event {
+                        kind: Kind::Blackout,
+                        interval: (open, close),
+                    });
+                    opened = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(open) = opened {
+            list.push(Event {
+                kind: Kind::Blackout,
+                interval: (open, end),
+            });
+        }
+
+        Ok(list)
+    }
+
+    /// The (julian date, next julian date) pairs every step from start to end, the last cut short at end
+    fn steps(start: f64, end: f64, step: f64) -> Result<Vec<(f64, f64)>, EventError> {
+        if !start.is_finite() || !end.is_finite() {
+            return Err(EventError::Bounds(start, end));
+        }
+
+        if !(step.is_finite() && step > 0.0) {
+            return Err(EventError::Step(step));
+        }
+
+        Ok((0..)
+            .map(|index| {
+                (
+                    start + index as f64 * step,
+                    start + (index + 1) as f64 * step,
+                )
+            })
+            .take_while(|(julian_date, _)| *julian_date < end)
+            .map(|(julian_date, next)| (julian_date, next.min(end)))
+            .collect())
+    }
+
+    /// The heliocentric states of earth and the body
+    fn states(body: &dyn Body, julian_date: f64) -> (StateVector, StateVector) {
+        (
+            Earth.heliocentric_state_at(julian_date),
+            body.heliocentric_state_at(julian_date),
+        )
+    }
+
+    /// The geocentric longitude of the body minus the sun's and the target, wrapped to (-180, 180]
+    fn separation(body: &dyn Body, julian_date: f64, target: f64) -> f64 {
+        let (earth, target_state) = Self::states(body, julian_date);
+        let (ex, ey, _) = earth.position;
+        let (bx, by, _) = target_state.position;
+
+        let body_longitude = (by - ey).atan2(bx - ex).to_degrees();
+        let sun_longitude = (-ey).atan2(-ex).to_degrees();
+
+        180.0 - (180.0 - (body_longitude - sun_longitude - target)).rem_euclid(360.0)
+    }
+
+    /// Tells whether the body passes in front of or behind the sun
+    fn conjunction(body: &dyn Body, julian_date: f64) -> Kind {
+        let (earth, target) = Self::states(body, julian_date);
+
+        match target.distance_to(&earth) < earth.distance {
+            true => Kind::InferiorConjunction,
+            false => Kind::SuperiorConjunction,
+        }
+    }
+
+    /// Bisects to the first julian date that the condition holds for
+    pub(crate) fn refine(mut low: f64, mut high: f64, condition: impl Fn(f64) -> bool) -> f64 {
+        let flipped = condition(low);
+
+        for _ in 0..REFINEMENTS {
+            let middle = (low + high) / 2.0;
+
+            match condition(middle) == flipped {
+                true => low = middle,
+                false => high = middle,
+            }
+        }
+
+        high
+    }
+}
@@ -0,0 +1,551 @@
+use std::{fmt::Write, str::FromStr};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{
+    conversions::military2standard,
+    datetime::{PlanetaryDateTime, Sols, Step},
+    instant::Instant,
+    kepler::{Body, Clock, Date, Time},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The pattern of [`PlanetaryDateTime::parse`], like `MY37-06-12T14:03:22 HT`
+pub const DEFAULT_PATTERN: &str = "MY%FT%T %Z";
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Error)]
+/// This is the collection of problems in a format pattern
+pub enum FormatError {
+    /// `%{0}` at byte {1} is not a directive
+    Directive(char, usize),
+    /// the pattern ends with a lone `%`
+    Incomplete,
+}
+
+#[derive(Display, Debug, Clone, PartialEq, Error)]
+/// This is the collection of problems when reading a planetary date time
+pub enum ParseError {
+    /// {0}
+    Pattern(#[from] FormatError),
+    /// expected `{0}` at byte {1}
+    Literal(String, usize),
+    /// expected the {0} at byte {1}
+    Value(&'static str, usize),
+    /// the {0} {1} is out of range
+    Range(&'static str, f64),
+    /// the {0} is missing
+    Missing(&'static str),
+    /// `{0}` is not a known {1}
+    Unknown(String, &'static str),
+    /// sol {0} at that time is not in month {1}
+    NotInMonth(u32, u8),
+    /// unexpected `{0}` at byte {1}
+    Trailing(String, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// This is the collection of pieces a [`Pattern`] is made of.
+pub enum Item {
+    /// Text that is shown as is
+    Literal(String),
+    /// `%Y` the year of the body
+    Year,
+    /// `%m` the month, `06`
+    Month,
+    /// `%B` the name of the month from [`Body::month_name`]
+    MonthName,
+    /// `%d` the sol of the month, `01` on the calendar sol the month's solar longitude starts in
+    Sol,
+    /// `%j` the sol of the year, `001`
+    SolOfYear,
+    /// `%L` the solar longitude in degrees, `251.3`
+    SolarLongitude,
+    /// `%q` the season
+    Season,
+    /// `%H` the hour of a 24 hour clock, `14`
+    Hour,
+    /// `%I` the hour of a 12 hour clock, `02`
+    Hour12,
+    /// `%M` the minute, `03`
+    Minute,
+    /// `%S` the second, `22`
+    Second,
+    /// `%p` the hour type, `AM` or `PM`
+    HourType,
+    /// `%Z` the code of the zone, `HT`
+    Code,
+    /// `%N` the name of the zone, `Hellas Time`
+    Name,
+}
+
+#[derive(Debug, Default, Clone)]
+/// This structure holds the values a [`Pattern`] can show.
+pub struct Fields {
+    /// ### The year of the body
+    pub year: i32,
+    /// ### The month of the year
+    pub month: u8,
+    /// ### The name of the month
+    pub month_name: String,
+    /// ### The sol of the month, 1 on the calendar sol the month's solar longitude starts in
+    pub sol: u32,
+    /// ### The sol of the year, starting at 1
+    pub sol_of_year: u32,
+    /// ### The solar longitude in degrees
+    pub ls: f64,
+    /// ### The season
+    pub season: String,
+    /// ### The time on a zone's clock
+    pub time: Time,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// This structure holds the values read by [`Pattern::parse`], `None` when the pattern has no directive for them.
+pub struct Parsed {
+    /// ### The year of the body
+    pub year: Option<i32>,
+    /// ### The month of the year
+    pub month: Option<u8>,
+    /// ### The name of the month
+    pub month_name: Option<String>,
+    /// ### The sol of the month
+    pub sol: Option<u32>,
+    /// ### The sol of the year
+    pub sol_of_year: Option<u32>,
+    /// ### The solar longitude in degrees
+    pub ls: Option<f64>,
+    /// ### The season
+    pub season: Option<String>,
+    /// ### The hour of a 24 hour clock
+    pub hour: Option<u32>,
+    /// ### The hour of a 12 hour clock
+    pub hour12: Option<u32>,
+    /// ### The minute
+    pub minute: Option<u32>,
+    /// ### The second
+    pub second: Option<u32>,
+    /// ### The hour type, `AM` or `PM`
+    pub hour_type: Option<String>,
+    /// ### The code or name of the zone
+    pub zone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// This structure is a strftime-like pattern for planetary dates and times.
+///
+/// | Directive | Shows |
+/// |-----------|-------|
+/// | `%Y` | year of the body |
+/// | `%m` | month, `06` |
+/// | `%B` | month name |
+/// | `%d` | sol of the month, `12` |
+/// | `%j` | sol of the year, `350` |
+/// | `%L` | solar longitude, `251.3` |
+/// | `%q` | season |
+/// | `%H` `%I` | hour of a 24 or 12 hour clock |
+/// | `%M` `%S` | minute and second |
+/// | `%p` | hour type, `AM` or `PM` |
+/// | `%Z` `%N` | zone code and name |
+/// | `%F` | `%Y-%m-%d` |
+/// | `%T` | `%H:%M:%S` |
+/// | `%%` | `%` |
+///
+/// ```rust
+/// use rust_solar::format::Pattern;
+///
+/// let pattern = Pattern::new("MY%Y-%m-%dT%T %Z").unwrap();
+/// assert_eq!(14, pattern.items.len());
+/// assert!(Pattern::new("%Y %x").is_err());
+/// ```
+pub struct Pattern {
+    /// ### The pieces of the pattern in order
+    pub items: Vec<Item>,
+}
+
+impl Pattern {
+    /// This method reads the directives of a pattern.
+    pub fn new(pattern: &str) -> Result<Self, FormatError> {
+        let mut items = vec![];
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices();
+
+        while let Some((_, char)) = chars.next() {
+            if char != '%' {
+                literal.push(char);
+                continue;
+            }
+
+            let (index, directive) = chars.next().ok_or(FormatError::Incomplete)?;
+            let expanded = match directive {
+                '%' => {
+                    literal.push('%');
+                    continue;
+                }
+                'Y' => vec![Item::Year],
+                'm' => vec![Item::Month],
+                'B' => vec![Item::MonthName],
+                'd' => vec![Item::Sol],
+                'j' => vec![Item::SolOfYear],
+                'L' => vec![Item::SolarLongitude],
+                'q' => vec![Item::Season],
+                'H' => vec![Item::Hour],
+                'I' => vec![Item::Hour12],
+                'M' => vec![Item::Minute],
+                'S' => vec![Item::Second],
+                'p' => vec![Item::HourType],
+                'Z' => vec![Item::Code],
+                'N' => vec![Item::Name],
+                'F' => vec![
+                    Item::Year,
+                    Item::Literal("-".to_string()),
+                    Item::Month,
+                    Item::Literal("-".to_string()),
+                    Item::Sol,
+                ],
+                'T' => vec![
+                    Item::Hour,
+                    Item::Literal(":".to_string()),
+                    Item::Minute,
+                    Item::Literal(":".to_string()),
+                    Item::Second,
+                ],
+                _ => return Err(FormatError::Directive(directive, index - 1)),
+            };
+
+            if !literal.is_empty() {
+                items.push(Item::Literal(std::mem::take(&mut literal)));
+            }
+            items.extend(expanded);
+        }
+
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        Ok(Self { items })
+    }
+
+    /// This method shows the fields as the pattern.
+    pub fn format(&self, fields: &Fields) -> String {
+        let time = &fields.time;
+        let mut text = String::new();
+
+        for item in &self.items {
+            // writing to a string does not fail
+            let _ = match item {
+                Item::Literal(literal) => write!(text, "{literal}"),
+                Item::Year => write!(text, "{}", fields.year),
+                Item::Month => write!(text, "{:02}", fields.month),
+                Item::MonthName => write!(text, "{}", fields.month_name),
+                Item::Sol => write!(text, "{:02}", fields.sol),
+                Item::SolOfYear => write!(text, "{:03}", fields.sol_of_year),
+                Item::SolarLongitude => write!(text, "{:.1}", fields.ls),
+                Item::Season => write!(text, "{}", fields.season),
+                Item::Hour => write!(text, "{:02}", time.hour),
+                Item::Hour12 => write!(text, "{:02}", military2standard(time.hour).0),
+                Item::Minute => write!(text, "{:02}", time.minute),
+                Item::Second => write!(text, "{:02}", time.second),
+                Item::HourType => write!(text, "{}", time.hour_type),
+                Item::Code => write!(text, "{}", time.code),
+                Item::Name => write!(text, "{}", time.name),
+            };
+        }
+
+        text
+    }
+
+    /// This method reads a text shown with the pattern.
+    ///
+    /// ```rust
+    /// use rust_solar::format::{Pattern, DEFAULT_PATTERN};
+    ///
+    /// let parsed = Pattern::new(DEFAULT_PATTERN).unwrap().parse("MY37-06-12T14:03:22 HT").unwrap();
+    ///
+    /// assert_eq!((Some(37), Some(6), Some(12)), (parsed.year, parsed.month, parsed.sol));
+    /// assert_eq!(Some("HT".to_string()), parsed.zone);
+    /// ```
+    pub fn parse(&self, text: &str) -> Result<Parsed, ParseError> {
+        let mut parsed = Parsed::default();
+        let mut at = 0;
+
+        for (index, item) in self.items.iter().enumerate() {
+            let rest = &text[at..];
+
+            // text values run up to the literal after them
+            let words = || match self.items.get(index + 1) {
+                Some(Item::Literal(literal)) => rest.find(literal.as_str()).unwrap_or(rest.len()),
+                Some(_) => rest
+                    .find(|char: char| char.is_whitespace() || char.is_ascii_digit())
+                    .unwrap_or(rest.len()),
+                None => rest.len(),
+            };
+            let digits = |most: usize, sign: bool, point: bool| {
+                rest.char_indices()
+                    .take_while(|&(index, char)| {
+                        char.is_ascii_digit()
+                            || (sign && index == 0 && (char == '-' || char == '+'))
+                            || (point && char == '.')
+                    })
+                    .take(most)
+                    .count()
+            };
+
+            let length = match item {
+                Item::Literal(literal) => match rest.starts_with(literal.as_str()) {
+                    true => literal.len(),
+                    false => return Err(ParseError::Literal(literal.clone(), at)),
+                },
+                Item::Year => digits(usize::MAX, true, false),
+                Item::SolOfYear => digits(3, false, false),
+                Item::SolarLongitude => digits(usize::MAX, false, true),
+                Item::HourType => match rest.get(..2).map(str::to_uppercase) {
+                    Some(hour_type) if hour_type == "AM" || hour_type == "PM" => 2,
+                    _ => 0,
+                },
+                Item::MonthName | Item::Season | Item::Code | Item::Name => words(),
+                _ => digits(2, false, false),
+            };
+
+            let value = &rest[..length];
+            let name = item.name();
+            if value.is_empty() {
+                return Err(ParseError::Value(name, at));
+            }
+
+            let number = || {
+                value
+                    .parse::<f64>()
+                    .map_err(|_| ParseError::Value(name, at))
+            };
+            let within = |low: f64, high: f64| match number()? {
+                number if (low..=high).contains(&number) => Ok(number),
+                number => Err(ParseError::Range(name, number)),
+            };
+
+            match item {
+                Item::Literal(_) => {}
+                Item::Year => parsed.year = Some(within(i32::MIN as f64, i32::MAX as f64)? as i32),
+                Item::Month => parsed.month = Some(within(1.0, 99.0)? as u8),
+                Item::MonthName => parsed.month_name = Some(value.to_string()),
+                Item::Sol => parsed.sol = Some(within(1.0, 99.0)? as u32),
+                Item::SolOfYear => parsed.sol_of_year = Some(within(1.0, 999.0)? as u32),
+                Item::SolarLongitude => parsed.ls = Some(within(0.0, 360.0)?),
+                Item::Season => parsed.season = Some(value.to_string()),
+                Item::Hour => parsed.hour = Some(within(0.0, 23.0)? as u32),
+                Item::Hour12 => parsed.hour12 = Some(within(1.0, 12.0)? as u32),
+                Item::Minute => parsed.minute = Some(within(0.0, 59.0)? as u32),
+                Item::Second => parsed.second = Some(within(0.0, 59.0)? as u32),
+                Item::HourType => parsed.hour_type = Some(value.to_uppercase()),
+                Item::Code | Item::Name => parsed.zone = Some(value.trim().to_string()),
+            }
+
+            at += length;
+        }
+
+        match at < text.len() {
+            true => Err(ParseError::Trailing(text[at..].to_string(), at)),
+            false => Ok(parsed),
+        }
+    }
+}
+
+impl Item {
+    /// This method gets the name of the value shown by the item, used in errors.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Literal(_) => "text",
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::MonthName => "month name",
+            Self::Sol => "sol",
+            Self::SolOfYear => "sol of the year",
+            Self::SolarLongitude => "solar longitude",
+            Self::Season => "season",
+            Self::Hour => "hour",
+            Self::Hour12 => "hour of a 12 hour clock",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::HourType => "hour type",
+            Self::Code => "zone code",
+            Self::Name => "zone name",
+        }
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = FormatError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Self::new(pattern)
+    }
+}
+
+impl<B: Body + Clone> PlanetaryDateTime<B> {
+    /// This method gets the values to show of the date time on a zone's clock.
+    ///
+    /// The date is the body's calendar date of the instant, the time is the zone's.
+    /// The clock shows the nearest second, so the date is taken at that second too,
+    /// and a time that rounds up to the next sol is shown on the next sol.
+    pub fn fields<Z: Clock>(&self, zone: &Z) -> Fields {
+        let seconds = zone.hours(self.julian_date()) * 3600.0;
+        let shown = Self::new(
+            self.body.clone(),
+            self.julian_date() + (seconds.round() - seconds) / 86400.0 * zone.day_length(),
+        );
+        let date = shown.date();
+        let start = shown.clone().month_start().date();
+
+        // a month may start in the year before
+        let days = self.body.orbital_period().ceil() * (date.year - start.year) as f64;
+
+        Fields {
+            year: date.year,
+            month: date.month,
+            month_name: self.body.month_name(date.month),
+            sol: (date.day - start.day + days) as u32 + 1,
+            sol_of_year: date.day as u32,
+            ls: date.ls,
+            season: date.season,
+            time: zone.time(shown.julian_date()),
+        }
+    }
+
+    /// This method shows the date time on a zone's clock with a [`Pattern`].
+    ///
+    /// ```rust
+    /// use rust_solar::datetime::PlanetaryDateTime;
+    /// use rust_solar::planets::mars::{Mars, Martian};
+    ///
+    /// // perseverance landed in the afternoon at jezero crater
+    /// let landing = PlanetaryDateTime::new(Mars, 2459264.37233);
+    /// let text = landing.format(&Martian::MTCp2, "%I:%M %p %Z (%N)").unwrap();
+    ///
+    /// assert_eq!("03:54 PM HT (Hellas Time)", text);
+    /// ```
+    pub fn format<Z: Clock>(&self, zone: &Z, pattern: &str) -> Result<String, FormatError> {
+        Ok(Pattern::new(pattern)?.format(&self.fields(zone)))
+    }
+}
+
+impl<B: Body + Clone> PlanetaryDateTime<B> {
+    /// This method reads a text shown with the [`DEFAULT_PATTERN`], with the zone it is in.
+    ///
+    /// ```rust
+    /// use rust_solar::datetime::PlanetaryDateTime;
+    /// use rust_solar::planets::mars::{Mars, Martian};
+    ///
+    /// let (time, zone) = PlanetaryDateTime::parse(Mars, "MY24-06-12T14:03:22 HT").unwrap();
+    ///
+    /// assert_eq!(Martian::MTCp2, zone);
+    /// assert_eq!("MY24-06-12T14:03:22 HT", time.format(&zone, "MY%FT%T %Z").unwrap());
+    /// ```
+    pub fn parse<Z: Clock + Clone + FromStr>(body: B, text: &str) -> Result<(Self, Z), ParseError> {
+        Self::parse_from_str(body, text, DEFAULT_PATTERN)
+    }
+
+    /// This method reads a text shown with a pattern, with the zone it is in.
+    pub fn parse_from_str<Z: Clock + Clone + FromStr>(
+        body: B,
+        text: &str,
+        pattern: &str,
+    ) -> Result<(Self, Z), ParseError> {
+        let parsed = Pattern::new(pattern)?.parse(text)?;
+        let zone = parsed.zone.as_deref().ok_or(ParseError::Missing("zone"))?;
+        let zone = zone
+            .parse::<Z>()
+            .map_err(|_| ParseError::Unknown(zone.to_string(), "zone"))?;
+
+        Ok((Self::from_parsed(body, &parsed, zone.clone())?, zone))
+    }
+
+    /// This method finds the instant of read values, the time is on the zone's clock.
+    pub fn from_parsed<Z: Clock>(body: B, parsed: &Parsed, zone: Z) -> Result<Self, ParseError> {
+        let year = parsed.year.ok_or(ParseError::Missing("year"))?;
+        let days = body.orbital_period().ceil();
+        let width = body.perihelion().avg_ls();
+
+        let month = match (parsed.month, &parsed.month_name) {
+            (Some(month), _) if month as f64 > (360.0 / width).round() => {
+                return Err(ParseError::Range("month", month as f64))
+            }
+            (Some(month), _) => Some(month),
+            (None, Some(name)) => Some(
+                (1..=(360.0 / width).round() as u8)
+                    .find(|&month| body.month_name(month).eq_ignore_ascii_case(name))
+                    .ok_or(ParseError::Unknown(name.clone(), "month name"))?,
+            ),
+            (None, None) => None,
+        };
+
+        let date = match (parsed.sol_of_year, month, parsed.sol) {
+            (Some(sol), _, _) if sol as f64 > days => {
+                return Err(ParseError::Range("sol of the year", sol as f64))
+            }
+            (Some(sol), _, _) => Date {
+                year,
+                day: sol as f64,
+                ..Default::default()
+            },
+            (None, Some(month), Some(sol)) => {
+                let first = Self::month_of(body.clone(), year, month).date();
+                let day = first.day + sol as f64 - 1.0;
+
+                match day > days {
+                    true => Date {
+                        year: first.year + 1,
+                        day: day - days,
+                        ..Default::default()
+                    },
+                    false => Date { day, ..first },
+                }
+            }
+            (None, None, _) => return Err(ParseError::Missing("month")),
+            (None, Some(_), None) => return Err(ParseError::Missing("sol")),
+        };
+
+        let hour = match (parsed.hour, parsed.hour12, parsed.hour_type.as_deref()) {
+            (Some(hour), _, _) => hour,
+            (None, Some(hour), Some("PM")) => hour % 12 + 12,
+            (None, Some(hour), Some(_)) => hour % 12,
+            (None, Some(_), None) => return Err(ParseError::Missing("hour type")),
+            (None, None, _) => 0,
+        };
+        let hours = hour as f64
+            + parsed.minute.unwrap_or(0) as f64 / 60.0
+            + parsed.second.unwrap_or(0) as f64 / 3600.0;
+
+        // the zone's clock reads the time once within the sol
+        let sol = body.rotational_period() / EARTH_ROTATIONAL_PERIOD;
+        let start = body.julian_date_of(&date);
+        let instant = Instant::at(zone, hours, start + sol / 2.0);
+        let found = Self::new(body, instant.julian_date);
+
+        match (month, parsed.sol) {
+            (Some(month), Some(sol)) if found.date().month != month => {
+                Err(ParseError::NotInMonth(sol, month))
+            }
+            _ => Ok(found),
+        }
+    }
+
+    /// This method finds the start of the month of a year, which may be in the year before.
+    fn month_of(body: B, year: i32, month: u8) -> Self {
+        let width = body.perihelion().avg_ls();
+        let start = body.julian_date_of(&Date {
+            year,
+            day: 1.0,
+            ..Default::default()
+        });
+        let half = body.orbital_period() * body.rotational_period() / EARTH_ROTATIONAL_PERIOD / 2.0;
+
+        let ls = (month as f64 - 1.0) * width;
+        let begins = Self::new(body, start - half).next_solar_longitude(ls);
+
+        // a month that ends before the year starts is the year before's
+        match begins.clone().advance(Step::Months(1)).julian_date() <= start {
+            true => (begins + Sols(1)).next_solar_longitude(ls),
+            false => begins,
+        }
+    }
+}
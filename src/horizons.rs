@@ -0,0 +1,227 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{
+    conversions::ASTRONOMICAL_UNIT,
+    ephemeris::Tabulated,
+    orbit::{ecliptic, norm, scale, OrbitalElements, StateVector},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+#[derive(Display, Debug, Error)]
+/// This is the collection of problems when reading JPL Horizons output
+pub enum HorizonsError {
+    /// could not read the file: {0}
+    Io(#[from] std::io::Error),
+    /// there is no $$SOE ... $$EOE block
+    NoData,
+    /// line {0}: the {1} value is missing or not a number
+    Field(usize, &'static str),
+    /// the elements are on the earth's equator, only ecliptic elements are supported
+    Plane,
+}
+
+/// A record of the data block, (line, julian date, values by column name)
+type Record = (usize, f64, HashMap<String, f64>);
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This structure reads the text and CSV output of [JPL Horizons](https://ssd.jpl.nasa.gov/horizons/).
+///
+/// The `Output units` and `Reference plane` of the header are honored,
+/// vectors on the earth's equator (`FRAME`) are rotated into the ecliptic.
+pub struct Horizons;
+
+impl Horizons {
+    /// This method reads a vector table from a file.
+    pub fn open_vectors(&self, path: impl AsRef<Path>) -> Result<Tabulated, HorizonsError> {
+        self.vectors(&fs::read_to_string(path)?)
+    }
+
+    /// This method reads osculating elements from a file.
+    pub fn open_elements(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<OrbitalElements>, HorizonsError> {
+        self.elements(&fs::read_to_string(path)?)
+    }
+
+    /// This method parses a vector table (X, Y, Z, VX, VY, VZ) into a tabulated ephemeris.
+    pub fn vectors(&self, text: &str) -> Result<Tabulated, HorizonsError> {
+        let (distance, time) = units(text);
+        let equatorial = text.contains("Reference plane : FRAME");
+
+        let mut rows = Vec::new();
+        for (line, julian_date, values) in records(text)? {
+            let field = |name: &'static str| {
+                values
+                    .get(name)
+                    .copied()
+                    .ok_or(HorizonsError::Field(line, name))
+            };
+
+            let mut position = scale((field("X")?, field("Y")?, field("Z")?), distance);
+            let mut velocity = scale((field("VX")?, field("VY")?, field("VZ")?), distance / time);
+
+            if equatorial {
+                (position, velocity) = (ecliptic(position), ecliptic(velocity));
+            }
+
+            rows.push((
+                julian_date,
+                StateVector {
+                    position,
+                    velocity,
+                    distance: norm(position),
+                },
+            ));
+        }
+
+        rows.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Ok(Tabulated { rows })
+    }
+
+    /// This method parses osculating elements, one body per epoch.
+    pub fn elements(&self, text: &str) -> Result<Vec<OrbitalElements>, HorizonsError> {
+        if text.contains("Reference plane : FRAME") {
+            return Err(HorizonsError::Plane);
+        }
+
+        let (distance, _) = units(text);
+        let name = text
+            .lines()
+            .find_map(|line| line.strip_prefix("Target body name:"))
+            .map(|name| {
+                name.split('{')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        records(text)?
+            .into_iter()
+            .map(|(line, julian_date, values)| {
+                let field = |name: &'static str| {
+                    values
+                        .get(name)
+                        .copied()
+                        .ok_or(HorizonsError::Field(line, name))
+                };
+
+                Ok(OrbitalElements {
+                    name: name.clone(),
+                    epoch: julian_date,
+                    semimajor: field("A")? * distance / ASTRONOMICAL_UNIT,
+                    eccentricity: field("EC")?,
+                    inclination: field("IN")?,
+                    ascending_node: field("OM")?,
+                    argument_of_perihelion: field("W")?,
+                    perihelion_time: field("Tp")?,
+                    rotational_period: EARTH_ROTATIONAL_PERIOD,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The kilometers in a distance unit and the seconds in a time unit
+fn units(text: &str) -> (f64, f64) {
+    let unit = text
+        .lines()
+        .find(|line| line.trim_start().starts_with("Output units"))
+        .unwrap_or_default();
+
+    let distance = match unit.contains("AU") {
+        true => ASTRONOMICAL_UNIT,
+        false => 1.0,
+    };
+    let time = match unit.contains("-D") {
+        true => EARTH_ROTATIONAL_PERIOD,
+        false => 1.0,
+    };
+
+    (distance, time)
+}
+
+/// Splits the data block into records, for both the text and the CSV layout
+fn records(text: &str) -> Result<Vec<Record>, HorizonsError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "$$SOE")
+        .ok_or(HorizonsError::NoData)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "$$EOE")
+            .ok_or(HorizonsError::NoData)?;
+
+    // the csv column names are above the block, "JDTDB, Calendar Date (TDB), X, Y, Z, ..."
+    let header: Option<Vec<String>> = lines[..start]
+        .iter()
+        .rev()
+        .find(|line| line.trim_start().starts_with("JD"))
+        .filter(|line| line.contains(','))
+        .map(|line| {
+            line.split(',')
+                .map(|name| name.trim().to_string())
+                .collect()
+        });
+
+    let mut list: Vec<Record> = Vec::new();
+    for (index, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let number = index + 1;
+
+        match &header {
+            Some(names) => {
+                let cells: Vec<&str> = line.split(',').map(str::trim).collect();
+                let julian_date = cells[0]
+                    .parse::<f64>()
+                    .map_err(|_| HorizonsError::Field(number, "julian date"))?;
+                let values = names
+                    .iter()
+                    .zip(cells.iter())
+                    .filter_map(|(name, cell)| Some((name.clone(), cell.parse::<f64>().ok()?)))
+                    .collect();
+
+                list.push((number, julian_date, values));
+            }
+            None => {
+                // "2451545.000000000 = A.D. 2000-Jan-01 12:00:00.0000 TDB" starts a record
+                let mut parts = line.splitn(2, '=');
+                if let (Ok(julian_date), Some(_)) = (
+                    parts.next().unwrap_or_default().trim().parse::<f64>(),
+                    parts.next(),
+                ) {
+                    list.push((number, julian_date, HashMap::new()));
+                    continue;
+                }
+
+                let record = list
+                    .last_mut()
+                    .ok_or(HorizonsError::Field(number, "julian date"))?;
+
+                // " X = 1.0E+08 Y =-2.0E+06" splits into keys ending and values starting each piece
+                let pieces: Vec<&str> = line.split('=').collect();
+                for pair in pieces.windows(2) {
+                    let key = pair[0].split_whitespace().last();
+                    let value = pair[1].split_whitespace().next();
+
+                    if let (Some(key), Some(value)) = (key, value) {
+                        let value = value
+                            .parse::<f64>()
+                            .map_err(|_| HorizonsError::Field(number, "value"))?;
+
+                        record.2.insert(key.to_string(), value);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(list)
+}
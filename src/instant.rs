@@ -0,0 +1,72 @@
+use crate::kepler::{Clock, Time};
+
+/// The times the search for a clock's reading is refined, one more than a DST jump needs
+const ITERATIONS: usize = 4;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// This structure is a moment in time as read on a zone's clock.
+///
+/// The moment is kept as a julian date (TT), so it is the same on every clock
+/// and [`Instant::with_zone`] only changes the clock that reads it.
+///
+/// ```rust
+/// use rust_solar::instant::Instant;
+/// use rust_solar::planets::mars::Martian;
+///
+/// // 14:00 Hellas Time on 2000-01-06 is 11:30 Arabia Time, a decisol west
+/// let hellas = Instant::at(Martian::MTCp2, 14.0, 2451549.5);
+/// let arabia = hellas.with_zone(Martian::MTCp1).time();
+///
+/// assert_eq!((11, 30, 0), (arabia.hour, arabia.minute, arabia.second));
+/// assert_eq!("ABT", arabia.code);
+/// ```
+pub struct Instant<Z> {
+    /// ### The julian date (TT) of the moment
+    pub julian_date: f64,
+    /// ### The zone whose clock reads the moment
+    pub zone: Z,
+}
+
+impl<Z: Clock> Instant<Z> {
+    /// This method creates the moment of a julian date (TT) in a zone.
+    pub fn new(julian_date: f64, zone: Z) -> Self {
+        Self { julian_date, zone }
+    }
+
+    /// This method finds the moment nearest a julian date (TT) that the zone's clock reads the hours.
+    pub fn at(zone: Z, hours: f64, near: f64) -> Self {
+        let mut julian_date = near;
+
+        for _ in 0..ITERATIONS {
+            let behind = (hours - zone.hours(julian_date) + 12.0).rem_euclid(24.0) - 12.0;
+            julian_date += behind / 24.0 * zone.day_length();
+        }
+
+        Self { julian_date, zone }
+    }
+
+    /// This method finds the moment nearest a julian date (TT) that the zone's clock shows the time.
+    pub fn from_time(zone: Z, time: &Time, near: f64) -> Self {
+        let hours = time.hour as f64 + time.minute as f64 / 60.0 + time.second as f64 / 3600.0;
+
+        Self::at(zone, hours, near)
+    }
+
+    /// This method reads the same moment on another zone's clock.
+    pub fn with_zone<T: Clock>(&self, zone: T) -> Instant<T> {
+        Instant {
+            julian_date: self.julian_date,
+            zone,
+        }
+    }
+
+    /// This method calculates the hours on the zone's clock.
+    pub fn hours(&self) -> f64 {
+        self.zone.hours(self.julian_date)
+    }
+
+    /// This method calculates the time on the zone's clock.
+    pub fn time(&self) -> Time {
+        self.zone.time(self.julian_date)
+    }
+}
@@ -5,6 +5,64 @@ use crate::planets::earth::{EarthCodes, EarthDate, EarthDateTime, EarthTime};
 /// The first Julian Date epoch
 pub const JD2NOON: f64 = 2451545.0;
 
+/// The seconds terrestrial time (TT) is ahead of international atomic time (TAI)
+pub const ATOMIC_OFFSET: f64 = 32.184;
+
+/// The unix times (UTC) each leap second took effect and the seconds TAI was ahead of UTC from then on (IERS Bulletin C)
+const LEAP_SECONDS: [(i64, f64); 28] = [
+    (63072000, 10.0),   // 1972-01-01
+    (78796800, 11.0),   // 1972-07-01
+    (94694400, 12.0),   // 1973-01-01
+    (126230400, 13.0),  // 1974-01-01
+    (157766400, 14.0),  // 1975-01-01
+    (189302400, 15.0),  // 1976-01-01
+    (220924800, 16.0),  // 1977-01-01
+    (252460800, 17.0),  // 1978-01-01
+    (283996800, 18.0),  // 1979-01-01
+    (315532800, 19.0),  // 1980-01-01
+    (362793600, 20.0),  // 1981-07-01
+    (394329600, 21.0),  // 1982-07-01
+    (425865600, 22.0),  // 1983-07-01
+    (489024000, 23.0),  // 1985-07-01
+    (567993600, 24.0),  // 1988-01-01
+    (631152000, 25.0),  // 1990-01-01
+    (662688000, 26.0),  // 1991-01-01
+    (709948800, 27.0),  // 1992-07-01
+    (741484800, 28.0),  // 1993-07-01
+    (773020800, 29.0),  // 1994-07-01
+    (820454400, 30.0),  // 1996-01-01
+    (867715200, 31.0),  // 1997-07-01
+    (915148800, 32.0),  // 1999-01-01
+    (1136073600, 33.0), // 2006-01-01
+    (1230768000, 34.0), // 2009-01-01
+    (1341100800, 35.0), // 2012-07-01
+    (1435708800, 36.0), // 2015-07-01
+    (1483228800, 37.0), // 2017-01-01
+];
+
+/// This function calculates the seconds terrestrial time (TT) is ahead of UTC at a unix time (UTC).
+///
+/// UTC before 1972 is held at the 10 seconds it started with,
+/// and the last leap second holds until the next one is announced.
+pub fn terrestrial_offset(unix: f64) -> f64 {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(start, _)| unix >= *start as f64)
+        .map_or(LEAP_SECONDS[0].1, |(_, leap)| *leap)
+        + ATOMIC_OFFSET
+}
+
+/// This function calculates the seconds terrestrial time (TT) is ahead of UTC at a unix time counted in TT.
+pub fn universal_offset(unix: f64) -> f64 {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(start, leap)| unix >= *start as f64 + leap + ATOMIC_OFFSET)
+        .map_or(LEAP_SECONDS[0].1, |(_, leap)| *leap)
+        + ATOMIC_OFFSET
+}
+
 #[derive(Default, Debug, Copy, Clone)]
 /// This structure contains julian date calculations
 pub struct Julian;
@@ -49,13 +107,13 @@ impl Julian {
 
     ///  your offset is decimal hours in military time: ex; 20.5 is 20:05pm is 8:05pm
     pub fn get_jd(self, year: i32, month: i32, day: i32, offset: f64) -> f64 {
-        let jd = (367 as f64 * year as f64
+        let jd = 367.0 * year as f64
             - (7 * (year + (month + 9) / 12) / 4) as f64
             - (((3 * (year + (month - 9) / 7) / 100) + 1) / 4) as f64
             + (275 * month / 9) as f64
             + day as f64
-            + 1721028.5 as f64
-            + offset as f64 / 24.0) as f64;
+            + 1721028.5
+            + offset / 24.0;
 
         println!("Julian date: {:?}", jd);
 
@@ -2,15 +2,19 @@ use displaydoc::Display;
 use strum::AsRefStr;
 
 use crate::{
-    orbit::{MeanMotion, Perihelion, Season, SemiAxis, SolarLongitude, Type},
+    anomaly::Anomaly,
+    conversions::{ASTRONOMICAL_UNIT, SOLAR_CONSTANT},
+    ephemeris::JULIAN_CENTURY,
+    julian::JD2NOON,
+    orbit::{
+        add, cross, dot, ecliptic, norm, scale, MeanMotion, Perihelion, Season, SemiAxis,
+        SolarLongitude, StateVector, Type, Vector,
+    },
     planets::EARTH_ROTATIONAL_PERIOD,
 };
 
 /// This trait acts as a common field for all planets, asteroids, moons, exo-planets, and comets
 ///
-/// ## Limitations
-/// `Only Solar`: Sidereal days is not supported
-///
 pub trait Body {
     /// Calculates the reference point which the body was discovered
     fn epoch(&self) -> f64;
@@ -28,6 +32,288 @@ pub trait Body {
     fn semiminor(&self) -> f64 {
         SemiAxis(self.semimajor()).minor(self.orbital_eccentricity())
     }
+    /// Calculates the tilt in degrees of the orbit against the ecliptic.
+    fn inclination(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the ecliptic longitude in degrees where the orbit crosses the ecliptic northward.
+    fn ascending_node(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the angle in degrees from the ascending node to the perihelion.
+    fn argument_of_perihelion(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the seconds in time it takes a body to rotate once against the stars.
+    ///
+    /// The solar day is longer because the body moves along its orbit,
+    /// bodies tilted past 90 degrees spin backwards so their solar day is shorter.
+    fn sidereal_period(&self) -> f64 {
+        let days = self.orbital_period();
+
+        match self.obliquity() > 90.0 {
+            true => self.rotational_period() * days / (days - 1.0),
+            false => self.rotational_period() * days / (days + 1.0),
+        }
+    }
+    /// Calculates the angle in degrees of the prime meridian from the vernal equinox at J2000.
+    fn prime_meridian(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the local sidereal time in hours at an east longitude (degrees).
+    ///
+    /// This is the right ascension overhead, so a star rises at the same sidereal time every day.
+    fn sidereal_time(&self, julian_date: f64, longitude: f64) -> f64 {
+        let turns = (julian_date - JD2NOON) * EARTH_ROTATIONAL_PERIOD / self.sidereal_period();
+        let angle = match self.obliquity() > 90.0 {
+            true => self.prime_meridian() - 360.0 * turns.fract(),
+            false => self.prime_meridian() + 360.0 * turns.fract(),
+        };
+
+        (angle + longitude).rem_euclid(360.0) / 15.0
+    }
+    /// Calculates the tilt in degrees of the body's equator against its orbit.
+    fn obliquity(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the solar longitude (Ls) in degrees at a julian date.
+    ///
+    /// Bodies that override this should put it in their dates with [`Date::with_solar_longitude`].
+    fn solar_longitude(&self, julian_date: f64) -> f64 {
+        SolarLongitude.compute(
+            Type::default().shape(self.orbital_eccentricity()),
+            self.orbit_day(julian_date),
+            self.orbital_eccentricity(),
+            self.perihelion_at(julian_date),
+            self.orbital_period(),
+            self.semimajor(),
+        )
+    }
+    /// Calculates the degrees the true sun is ahead of the mean sun, a sundial minus a clock.
+    ///
+    /// The eccentricity makes the sun run ahead and behind along the orbit,
+    /// and the obliquity turns even steps along the orbit into uneven steps along the equator.
+    fn equation_of_time(&self, julian_date: f64) -> f64 {
+        let ls = self.solar_longitude(julian_date);
+        let peri = self.perihelion_at(julian_date);
+        let mean = MeanMotion::by(
+            &mut MeanMotion,
+            self.orbit_day(julian_date),
+            peri,
+            self.orbital_period(),
+        );
+
+        let center = ls - peri.perihelion - mean.to_degrees();
+        let right_ascension = (self.obliquity().to_radians().cos() * ls.to_radians().sin())
+            .atan2(ls.to_radians().cos())
+            .to_degrees();
+
+        let wrap = |angle: f64| (angle + 180.0).rem_euclid(360.0) - 180.0;
+
+        wrap(ls - right_ascension) - wrap(center)
+    }
+    /// Calculates the (latitude, east longitude) in degrees where the sun is overhead.
+    fn subsolar_point(&self, julian_date: f64) -> (f64, f64) {
+        let ls = self.solar_longitude(julian_date).to_radians();
+        let obliquity = self.obliquity().to_radians();
+
+        let declination = (obliquity.sin() * ls.sin()).asin();
+        let right_ascension = (obliquity.cos() * ls.sin()).atan2(ls.cos()).to_degrees();
+        let longitude = right_ascension - 15.0 * self.sidereal_time(julian_date, 0.0);
+
+        (
+            declination.to_degrees(),
+            (longitude + 180.0).rem_euclid(360.0) - 180.0,
+        )
+    }
+    /// Calculates the local true solar time in hours at an east longitude (degrees).
+    fn solar_time(&self, julian_date: f64, longitude: f64) -> f64 {
+        let (_, subsolar) = self.subsolar_point(julian_date);
+
+        (12.0 + (longitude - subsolar) / 15.0).rem_euclid(24.0)
+    }
+    /// Calculates the local mean solar time in hours at an east longitude (degrees).
+    fn mean_solar_time(&self, julian_date: f64, longitude: f64) -> f64 {
+        (self.solar_time(julian_date, longitude) - self.equation_of_time(julian_date) / 15.0)
+            .rem_euclid(24.0)
+    }
+    /// Calculates the brightness of the host star in solar luminosities.
+    fn luminosity(&self) -> f64 {
+        1.0
+    }
+    /// Calculates the flux (W/m²) of sunlight at the top of the atmosphere at a julian date.
+    fn solar_flux(&self, julian_date: f64) -> f64 {
+        let distance = self.heliocentric_state_at(julian_date).distance / ASTRONOMICAL_UNIT;
+
+        SOLAR_CONSTANT * self.luminosity() / distance.powi(2)
+    }
+    /// Calculates the flux (W/m²) on flat ground at the top of the atmosphere at a latitude and east longitude.
+    fn irradiance(&self, julian_date: f64, latitude: f64, longitude: f64) -> f64 {
+        let (declination, subsolar) = self.subsolar_point(julian_date);
+        let (latitude, declination) = (latitude.to_radians(), declination.to_radians());
+
+        let elevation = latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * (longitude - subsolar).to_radians().cos();
+
+        self.solar_flux(julian_date) * elevation.max(0.0)
+    }
+    /// Calculates the daily mean flux (W/m²) at the top of the atmosphere at a latitude and solar longitude.
+    ///
+    /// Moons use the distance of their host from the sun.
+    fn insolation(&self, latitude: f64, ls: f64) -> f64 {
+        let (semimajor, eccentricity, perihelion) = match self.host() {
+            Some(host) => (
+                host.semimajor(),
+                host.orbital_eccentricity(),
+                host.perihelion().perihelion,
+            ),
+            None => (
+                self.semimajor(),
+                self.orbital_eccentricity(),
+                self.perihelion().perihelion,
+            ),
+        };
+        let distance = semimajor * (1.0 - eccentricity.powi(2))
+            / (1.0 + eccentricity * (ls - perihelion).to_radians().cos());
+
+        let declination = (self.obliquity().to_radians().sin() * ls.to_radians().sin()).asin();
+        let latitude = latitude.to_radians();
+
+        // the hour angle of sunset, 0 for polar nights and π for polar days
+        let sunset = (-latitude.tan() * declination.tan()).clamp(-1.0, 1.0).acos();
+
+        SOLAR_CONSTANT * self.luminosity() / distance.powi(2) / std::f64::consts::PI
+            * (sunset * latitude.sin() * declination.sin()
+                + latitude.cos() * declination.cos() * sunset.sin())
+    }
+    /// Calculates the julian date at the start of a date in the body's calendar.
+    fn julian_date_of(&self, date: &Date) -> f64 {
+        let days = (date.year as f64 - 12.0) * self.orbital_period() + date.day - 1.0;
+
+        self.epoch() + days * self.rotational_period() / EARTH_ROTATIONAL_PERIOD
+    }
+    /// Calculates the name of the body, the name of its type by default.
+    fn name(&self) -> String {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+
+        name.rsplit("::").next().unwrap_or(name).to_string()
+    }
+    /// Calculates the name of a month of the body's calendar, months are numbered by default.
+    fn month_name(&self, month: u8) -> String {
+        format!("Month {month}")
+    }
+    /// Calculates the (right ascension, declination) in degrees of the north pole, defaults to earth's.
+    fn pole(&self) -> (f64, f64) {
+        (0.0, 90.0)
+    }
+    /// Calculates the (right ascension, declination) drift in degrees per julian century of the north pole.
+    fn pole_precession(&self) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+    /// Calculates the (right ascension, declination) in degrees of the north pole drifted to a julian date.
+    fn pole_at(&self, julian_date: f64) -> (f64, f64) {
+        let centuries = (julian_date - JD2NOON) / JULIAN_CENTURY;
+        let ((right_ascension, declination), (ascension_rate, declination_rate)) =
+            (self.pole(), self.pole_precession());
+
+        (
+            right_ascension + ascension_rate * centuries,
+            declination + declination_rate * centuries,
+        )
+    }
+    /// The body this body orbits, `None` when the host body is the sun.
+    fn host(&self) -> Option<&'static dyn Body> {
+        None
+    }
+    /// Calculates the (inclination, ascending node, argument of perihelion) in degrees against the host's equator.
+    ///
+    /// The host's equator is placed by its pole, `None` when the host body is the sun.
+    fn equatorial_elements(&self) -> Option<(f64, f64, f64)> {
+        let [x, y, z] = equator(self.host()?.pole());
+        let (inclination, node, argument) = (
+            self.inclination().to_radians(),
+            self.ascending_node().to_radians(),
+            self.argument_of_perihelion().to_radians(),
+        );
+
+        // the orbit's pole and the direction of its perihelion in the ecliptic
+        let normal = (
+            inclination.sin() * node.sin(),
+            -inclination.sin() * node.cos(),
+            inclination.cos(),
+        );
+        let periapsis = (
+            node.cos() * argument.cos() - node.sin() * argument.sin() * inclination.cos(),
+            node.sin() * argument.cos() + node.cos() * argument.sin() * inclination.cos(),
+            argument.sin() * inclination.sin(),
+        );
+        let local = |v: Vector| (dot(v, x), dot(v, y), dot(v, z));
+        let (normal, periapsis) = (local(normal), local(periapsis));
+
+        let node = normal.0.atan2(-normal.1);
+        let ascending = (node.cos(), node.sin(), 0.0);
+        let argument = dot(cross(ascending, periapsis), normal).atan2(dot(ascending, periapsis));
+
+        Some((
+            normal.2.clamp(-1.0, 1.0).acos().to_degrees(),
+            node.to_degrees().rem_euclid(360.0),
+            argument.to_degrees().rem_euclid(360.0),
+        ))
+    }
+    /// Calculates the second zonal harmonic (J2) which measures how oblate the body is.
+    fn oblateness(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the radius in kilometers at the equator.
+    fn equatorial_radius(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the (argument of perihelion, ascending node) drift in degrees per julian century.
+    ///
+    /// Moons precess from the oblateness of their host, about the host's pole and measured on its equator,
+    /// other bodies should override with tabulated rates.
+    fn precession(&self) -> (f64, f64) {
+        match self.host().zip(self.equatorial_elements()) {
+            Some((host, (inclination, _, _))) => {
+                let semi_latus = self.semimajor() * (1.0 - self.orbital_eccentricity().powi(2));
+                let ratio = host.equatorial_radius() / (semi_latus * ASTRONOMICAL_UNIT);
+                let cosine = inclination.to_radians().cos();
+                let motion = 360.0 * JULIAN_CENTURY * EARTH_ROTATIONAL_PERIOD
+                    / (self.orbital_period() * self.rotational_period());
+                let factor = motion * host.oblateness() * ratio.powi(2);
+
+                (
+                    0.75 * factor * (5.0 * cosine.powi(2) - 1.0),
+                    -1.5 * factor * cosine,
+                )
+            }
+            None => (0.0, 0.0),
+        }
+    }
+    /// Calculates the drift in degrees per julian century of the perihelion's solar longitude.
+    ///
+    /// This defaults to the longitude of perihelion's drift, bodies whose equinox precesses should override it.
+    fn perihelion_precession(&self) -> f64 {
+        let (argument, node) = self.precession();
+
+        argument + node
+    }
+    /// Calculates the perihelion with its solar longitude drifted to a julian date.
+    fn perihelion_at(&self, julian_date: f64) -> Perihelion {
+        let drift = self.perihelion_precession() * (julian_date - JD2NOON) / JULIAN_CENTURY;
+        let peri = self.perihelion();
+
+        Perihelion {
+            perihelion: (peri.perihelion + drift).rem_euclid(360.0),
+            ..peri
+        }
+    }
+    /// Calculates the days (in the body's own days) since the start of the current orbit.
+    fn orbit_day(&self, julian_date: f64) -> f64 {
+        ((julian_date - self.epoch()) * EARTH_ROTATIONAL_PERIOD / self.rotational_period())
+            .rem_euclid(self.orbital_period())
+    }
     /// Calculates the mean motion which is the perihelian elapse.
     fn mean_motion(&mut self, day: f64) -> f64 {
         MeanMotion::by(
@@ -43,12 +329,97 @@ pub trait Body {
             julian_date,
             self.epoch(),
             self.rotational_period(),
-            self.perihelion(),
+            self.perihelion_at(julian_date),
             self.semimajor(),
             self.orbital_eccentricity(),
             self.orbital_period(),
         )
     }
+    /// Calculates the position and velocity (ecliptic J2000) around its host at a julian date.
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        let shape = Type::default().shape(self.orbital_eccentricity());
+        let theta = Anomaly.truly(
+            shape,
+            self.orbit_day(julian_date),
+            self.orbital_eccentricity(),
+            self.perihelion(),
+            self.orbital_period(),
+            self.semimajor(),
+        );
+        let centuries = (julian_date - JD2NOON) / JULIAN_CENTURY;
+        let (argument, node) = self.precession();
+        let orbit = |(inclination, ascending_node, argument_of_perihelion): (f64, f64, f64)| {
+            StateVector::default().compute(
+                theta,
+                self.orbital_eccentricity(),
+                self.semimajor(),
+                self.orbital_period() * self.rotational_period(),
+                (
+                    inclination,
+                    ascending_node + node * centuries,
+                    argument_of_perihelion + argument * centuries,
+                ),
+            )
+        };
+
+        // moons precess on their host's equator, then turn back into the ecliptic
+        match self.host().zip(self.equatorial_elements()) {
+            Some((host, elements)) => {
+                let [x, y, z] = equator(host.pole());
+                let state = orbit(elements);
+                let turn = |(a, b, c): Vector| add(add(scale(x, a), scale(y, b)), scale(z, c));
+
+                StateVector {
+                    position: turn(state.position),
+                    velocity: turn(state.velocity),
+                    distance: state.distance,
+                }
+            }
+            None => orbit((
+                self.inclination(),
+                self.ascending_node(),
+                self.argument_of_perihelion(),
+            )),
+        }
+    }
+    /// Calculates the position and velocity from the sun, adding the host's state for moons.
+    fn heliocentric_state_at(&self, julian_date: f64) -> StateVector {
+        let state = self.state_at(julian_date);
+
+        match self.host() {
+            Some(host) => {
+                let origin = host.heliocentric_state_at(julian_date);
+                let position = add(origin.position, state.position);
+
+                StateVector {
+                    position,
+                    velocity: add(origin.velocity, state.velocity),
+                    distance: norm(position),
+                }
+            }
+            None => state,
+        }
+    }
+}
+
+
+/// The axes of a body's equator in the ecliptic, (node, 90° along the equator, pole)
+fn equator(pole: (f64, f64)) -> [Vector; 3] {
+    let (right_ascension, declination) = (pole.0.to_radians(), pole.1.to_radians());
+    let z = ecliptic((
+        declination.cos() * right_ascension.cos(),
+        declination.cos() * right_ascension.sin(),
+        declination.sin(),
+    ));
+
+    // the equator crosses the ecliptic northward at its ascending node
+    let node = cross((0.0, 0.0, 1.0), z);
+    let x = match norm(node) > 1e-12 {
+        true => scale(node, 1.0 / norm(node)),
+        false => (1.0, 0.0, 0.0),
+    };
+
+    [x, cross(z, x), z]
 }
 
 
@@ -92,6 +463,7 @@ impl Date {
     /// Some planets may have different dates for seasons,
     /// the seasons are baesd on the 360 orbital path.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn compute(
         &self,
         julian_date: f64,
@@ -144,6 +516,18 @@ impl Date {
             season,
         }
     }
+
+    /// This method replaces the solar longitude, and the month and season that follow from it.
+    ///
+    /// Bodies with a better solar longitude than the two-body one put it in their dates with this.
+    pub fn with_solar_longitude(self, ls: f64, mut peri: Perihelion) -> Self {
+        Self {
+            month: (1.0 + (ls / peri.avg_ls()).floor()) as u8,
+            season: Season::default().from(ls as u32),
+            ls,
+            ..self
+        }
+    }
 }
 
 
@@ -217,6 +601,73 @@ pub trait TimeZone {
 }
 
 
+/// This trait maps the longitudes of a body to its time zones.
+///
+/// Zones are bands of east longitude, `[east, west)` in degrees,
+/// a band may wrap across ±180° when its east bound is greater than its west bound.
+/// Longitudes are normalized to `[-180, 180)`, so 180° belongs to the band holding -180°.
+///
+pub trait ZoneMap: Sized + Copy + 'static {
+    /// This method gets every zone of the body.
+    fn zones() -> &'static [Self];
+    /// This method gets the (east, west) longitudes in degrees that bound the zone.
+    fn bounds(&self) -> (f64, f64);
+    /// This method checks if an east longitude (degrees) is within the zone.
+    fn contains(&self, longitude: f64) -> bool {
+        let longitude = (longitude + 180.0).rem_euclid(360.0) - 180.0;
+        let (east, west) = self.bounds();
+
+        match east <= west {
+            true => east <= longitude && longitude < west,
+            false => east <= longitude || longitude < west,
+        }
+    }
+    /// This method finds the zone of an east longitude (degrees), `None` when it falls in a gap.
+    fn from_longitude(longitude: f64) -> Option<Self> {
+        Self::zones()
+            .iter()
+            .find(|zone| zone.contains(longitude))
+            .copied()
+    }
+}
+
+
+/// This trait reads the clock of a time zone at any julian date (TT), not only now.
+///
+/// Clocks of different zones, even on different bodies, read the same instant,
+/// so a time on one clock can be shown on another with [`crate::instant::Instant`].
+///
+pub trait Clock {
+    /// This method gets the earth days in the 24 hours of the zone's clock.
+    fn day_length(&self) -> f64;
+    /// This method calculates the hours on the zone's clock at a julian date (TT).
+    fn hours(&self, julian_date: f64) -> f64;
+    /// This method gets the (code, name, offset name) of the zone at a julian date (TT).
+    fn labels(&self, julian_date: f64) -> (String, String, String);
+    /// This method gets the text the zone is read back from, its code by default.
+    fn identifier(&self) -> String {
+        self.labels(JD2NOON).0
+    }
+    /// This method calculates the time on the zone's clock at a julian date (TT).
+    fn time(&self, julian_date: f64) -> Time {
+        // rounded to the second so 14:00 does not show as 13:59:59
+        let seconds = (self.hours(julian_date) * 3600.0).round().rem_euclid(86400.0) as u32;
+        let hour = seconds / 3600;
+        let (code, name, offset_name) = self.labels(julian_date);
+
+        Time {
+            hour: hour as i32,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+            code,
+            name,
+            offset_name,
+            hour_type: HourType::new(&HourType::Unknown, hour as u8),
+        }
+    }
+}
+
+
 #[derive(Display, Debug, Clone, Copy, Default, AsRefStr)]
 /// The hour type of the timezone
 pub enum HourType {
@@ -236,6 +687,7 @@ impl HourType {
     /// This method computes the hour type of the time given an hour.
     /// 
     /// This is according to military time not standard time
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(&self, hour: u8) -> String {
         match hour {
             0..=11 => Self::AM,
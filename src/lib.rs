@@ -6,7 +6,6 @@
     // trivial_numeric_casts,
     unsafe_code,
     unstable_features,
-    missing_docs,
     unused_import_braces,
     unused_qualifications
 )]
@@ -16,7 +15,7 @@
 //! Please know this may not be 100% accurate as these data rely on 30% hypothetical data.
 //!
 //! > Rust Solar is a full fledged library to help programmers utilize calendar, date, and time for celestial bodies
-//! in their application. Down below are some links that helped my research.
+//! > in their application. Down below are some links that helped my research.
 //!
 //! 
 //! # References Used
@@ -44,6 +43,7 @@
 //!  - `exo-planets`: Brings exo-planet support
 //!  - `comets`: Brings comet support
 //!  - `moons`: Brings moon support
+//!  - `config`: Brings hypothetical bodies from TOML and JSON files, not enabled by default
 //!
 //! 
 //! 
@@ -83,3 +83,49 @@ pub mod anomaly;
 
 /// This module contains julian operations
 pub mod julian;
+
+/// This module contains light travel time calculations
+pub mod light;
+
+/// This module contains conjunction and opposition searches
+pub mod events;
+
+/// This module contains transfer orbit calculations
+pub mod transfer;
+
+/// This module contains analytic planetary ephemerides
+pub mod ephemeris;
+
+/// This module contains the truncated VSOP87 planetary theory
+pub mod vsop87;
+
+/// This module contains JPL SPK kernel reading
+pub mod spk;
+
+/// This module contains minor planet center parsing
+pub mod mpc;
+
+/// This module contains JPL Horizons parsing
+pub mod horizons;
+
+/// This module contains n-body integration
+pub mod nbody;
+
+/// This module contains hypothetical bodies loaded from configuration
+#[cfg(feature="config")]
+pub mod config;
+
+/// This module contains sunrise and sunset calculations
+pub mod daylight;
+
+/// This module contains zone aware instants
+pub mod instant;
+
+/// This module contains planetary date times
+pub mod datetime;
+
+/// This module contains formatting of planetary dates and times
+pub mod format;
+
+/// This module contains canonical timestamps for exchanging planetary date times
+pub mod canonical;
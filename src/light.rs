@@ -0,0 +1,118 @@
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{conversions::SPEED_OF_LIGHT, kepler::Body, planets::EARTH_ROTATIONAL_PERIOD};
+
+/// The iterations it takes for the light-time correction to settle below a millisecond
+const CORRECTIONS: usize = 4;
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Error)]
+/// This is the collection of problems with the dates of a light time timeline
+pub enum LightError {
+    /// the step of {0} days must be a positive number
+    Step(f64),
+    /// the dates from {0} to {1} must be finite
+    Bounds(f64, f64),
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains the distance and light travel time between two bodies.
+pub struct LightTime {
+    /// ### The julian date the signal is received
+    pub julian_date: f64,
+    /// ### The distance between the bodies in kilometers
+    pub distance: f64,
+    /// ### The time in seconds for light to travel one way
+    pub one_way: f64,
+    /// ### The time in seconds for light to travel there and back
+    pub round_trip: f64,
+}
+
+impl LightTime {
+    /// This method computes the light time of a signal sent from one body and received by another.
+    ///
+    /// When `corrected` is true, the sender is placed where it was when the signal left
+    /// rather than where it is when the signal arrives (iterated light-time correction).
+    ///
+    /// > - ![Light Time](https://latex.codecogs.com/svg.image?\tau=\frac{|r_{to}(t)-r_{from}(t-\tau)|}{c})
+    ///
+    pub fn between(
+        &self,
+        from: &dyn Body,
+        to: &dyn Body,
+        julian_date: f64,
+        corrected: bool,
+    ) -> Self {
+        let (distance, one_way) = Self::travel(from, to, julian_date, corrected);
+
+        let round_trip = match corrected {
+            true => {
+                // the echo arrives back at the sender on the julian date after bouncing off the receiver
+                let (_, back) = Self::travel(to, from, julian_date, corrected);
+                let (_, there) = Self::travel(
+                    from,
+                    to,
+                    julian_date - back / EARTH_ROTATIONAL_PERIOD,
+                    corrected,
+                );
+
+                there + back
+            }
+            false => one_way * 2.0,
+        };
+
+        Self {
+            julian_date,
+            distance,
+            one_way,
+            round_trip,
+        }
+    }
+
+    /// This method generates the light time from `start` to `end` every `step` (earth days).
+    ///
+    /// The step must be positive and the dates finite, or the timeline is refused.
+    pub fn timeline(
+        &self,
+        from: &dyn Body,
+        to: &dyn Body,
+        start: f64,
+        end: f64,
+        step: f64,
+        corrected: bool,
+    ) -> Result<Vec<Self>, LightError> {
+        if !start.is_finite() || !end.is_finite() {
+            return Err(LightError::Bounds(start, end));
+        }
+
+        if !(step.is_finite() && step > 0.0) {
+            return Err(LightError::Step(step));
+        }
+
+        Ok((0..)
+            .map(|index| start + index as f64 * step)
+            .take_while(|julian_date| *julian_date <= end)
+            .map(|julian_date| self.between(from, to, julian_date, corrected))
+            .collect())
+    }
+
+    /// The distance and seconds for light to travel from `from` to `to` at the julian date
+    fn travel(from: &dyn Body, to: &dyn Body, julian_date: f64, corrected: bool) -> (f64, f64) {
+        let receiver = to.heliocentric_state_at(julian_date);
+        let mut distance = from
+            .heliocentric_state_at(julian_date)
+            .distance_to(&receiver);
+
+        if corrected {
+            for _ in 0..CORRECTIONS {
+                let tau = distance / SPEED_OF_LIGHT / EARTH_ROTATIONAL_PERIOD;
+
+                distance = from
+                    .heliocentric_state_at(julian_date - tau)
+                    .distance_to(&receiver);
+            }
+        }
+
+        (distance, distance / SPEED_OF_LIGHT)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    conversions::ASTRONOMICAL_UNIT, julian::JD2NOON, kepler::Body, orbit::Perihelion,
+    planets::jupiter::Jupiter,
+};
+
+#[derive(Debug, Copy, Clone)]
+/// This structure represents the innermost galilean moon of jupiter
+///
+/// The elements are JPL's mean elements (2000 January 1.5) on jupiter's equator,
+/// the orbit is placed in the ecliptic by jupiter's pole.
+pub struct Io;
+
+impl Body for Io {
+    /// A.D 2000 January 1, 12:00:00 (J2000)
+    fn epoch(&self) -> f64 {
+        JD2NOON
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        0.0041
+    }
+
+    /// The 1.769138 earth days around jupiter in the ionian solar day
+    fn orbital_period(&self) -> f64 {
+        0.999_592
+    }
+
+    /// Io is tidally locked, the sun takes a little longer than an orbit to return
+    fn rotational_period(&self) -> f64 {
+        152_915.9
+    }
+
+    /// The mean anomaly is 330.9° at the epoch, the "ls" counts the mean motion from the epoch
+    fn perihelion(&self) -> Perihelion {
+        Perihelion {
+            month: (0.0, 0.083_3),
+            ls: (0.0, 30.0),
+            perihelion: 29.1,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        421_800.0 / ASTRONOMICAL_UNIT
+    }
+
+    /// Jupiter's equator against the ecliptic
+    fn inclination(&self) -> f64 {
+        2.2165
+    }
+
+    /// Jupiter's equator crosses the ecliptic northward
+    fn ascending_node(&self) -> f64 {
+        337.8177
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        49.1
+    }
+
+    fn sidereal_period(&self) -> f64 {
+        152_853.5
+    }
+
+    /// Io shares jupiter's equator, so its seasons are jupiter's
+    fn solar_longitude(&self, julian_date: f64) -> f64 {
+        Jupiter.solar_longitude(julian_date)
+    }
+
+    fn pole(&self) -> (f64, f64) {
+        (268.05, 64.50)
+    }
+
+    fn equatorial_radius(&self) -> f64 {
+        1821.6
+    }
+
+    fn host(&self) -> Option<&'static dyn Body> {
+        Some(&Jupiter)
+    }
+}
@@ -0,0 +1,206 @@
+use std::{fs, path::Path};
+
+use chrono::NaiveDate;
+use displaydoc::Display;
+use julian_day_converter::JULIAN_DAY_UNIX_EPOCH_DAYS;
+use thiserror::Error;
+
+use crate::{orbit::OrbitalElements, planets::EARTH_ROTATIONAL_PERIOD};
+
+#[derive(Display, Debug, Error)]
+/// This is the collection of problems when reading minor planet center files
+pub enum MpcError {
+    /// could not read the file: {0}
+    Io(#[from] std::io::Error),
+    /// line {0}: the {1} column is missing or not a number
+    Column(usize, &'static str),
+    /// line {0}: the epoch `{1}` is not a valid packed date
+    Epoch(usize, String),
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains the bodies read from a minor planet center file.
+pub struct Catalog {
+    /// ### The bodies on elliptical orbits
+    pub bodies: Vec<OrbitalElements>,
+    /// ### The (line, name, eccentricity) of the parabolic and hyperbolic orbits that were skipped
+    pub unbound: Vec<(usize, String, f64)>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This structure reads the [Minor Planet Center](https://minorplanetcenter.net/iau/info/MPOrbitFormat.html)
+/// orbit files into bodies.
+pub struct Mpc;
+
+impl Mpc {
+    /// This method reads the asteroids of a `MPCORB.DAT` file.
+    pub fn open_asteroids(&self, path: impl AsRef<Path>) -> Result<Catalog, MpcError> {
+        self.asteroids(&fs::read_to_string(path)?)
+    }
+
+    /// This method reads the comets of a `CometEls.txt` file.
+    pub fn open_comets(&self, path: impl AsRef<Path>) -> Result<Catalog, MpcError> {
+        self.comets(&fs::read_to_string(path)?)
+    }
+
+    /// This method parses the packed MPCORB format, one asteroid per line.
+    ///
+    /// The header, everything before the line of dashes, and blank lines are skipped.
+    /// Orbits that are not elliptical are listed as unbound rather than read.
+    pub fn asteroids(&self, text: &str) -> Result<Catalog, MpcError> {
+        let start = text
+            .lines()
+            .position(|line| line.starts_with("-----"))
+            .map_or(0, |dashes| dashes + 1);
+
+        let mut catalog = Catalog::default();
+        for (index, line) in text.lines().enumerate().skip(start) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let number = index + 1;
+            let column = |range: std::ops::Range<usize>, name: &'static str| {
+                line.get(range)
+                    .and_then(|field| field.trim().parse::<f64>().ok())
+                    .ok_or(MpcError::Column(number, name))
+            };
+
+            let packed = line.get(20..25).unwrap_or_default();
+            let epoch = unpack_epoch(packed).ok_or(MpcError::Epoch(number, packed.to_string()))?;
+            let name = line
+                .get(166..194)
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .unwrap_or(line.get(0..7).unwrap_or_default().trim());
+
+            let eccentricity = column(70..79, "eccentricity")?;
+            if !(0.0..1.0).contains(&eccentricity) {
+                catalog
+                    .unbound
+                    .push((number, name.to_string(), eccentricity));
+                continue;
+            }
+
+            catalog.bodies.push(OrbitalElements::from_mean_anomaly(
+                name.to_string(),
+                epoch,
+                column(92..103, "semi-major axis")?,
+                eccentricity,
+                (
+                    column(59..68, "inclination")?,
+                    column(48..57, "ascending node")?,
+                    column(37..46, "argument of perihelion")?,
+                ),
+                column(26..35, "mean anomaly")?,
+            ));
+        }
+
+        Ok(catalog)
+    }
+
+    /// This method parses the CometEls format, one comet per line.
+    ///
+    /// The semi-major axis comes from the perihelion distance,
+    /// parabolic and hyperbolic comets have none so they are listed as unbound rather than read.
+    pub fn comets(&self, text: &str) -> Result<Catalog, MpcError> {
+        let mut catalog = Catalog::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let number = index + 1;
+            let column = |range: std::ops::Range<usize>, name: &'static str| {
+                line.get(range)
+                    .and_then(|field| field.trim().parse::<f64>().ok())
+                    .ok_or(MpcError::Column(number, name))
+            };
+
+            let day = column(22..29, "perihelion day")?;
+            let perihelion_time = calendar(
+                column(14..18, "perihelion year")? as i32,
+                column(19..21, "perihelion month")? as u32,
+                day,
+            )
+            .ok_or(MpcError::Epoch(
+                number,
+                line.get(14..29).unwrap_or_default().to_string(),
+            ))?;
+
+            // comets without an epoch are given at perihelion
+            let epoch = match line.get(81..89).map(str::trim) {
+                Some(packed) if !packed.is_empty() => {
+                    unpack_date(packed).ok_or(MpcError::Epoch(number, packed.to_string()))?
+                }
+                _ => perihelion_time,
+            };
+
+            let name = line
+                .get(102..158)
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .unwrap_or(line.get(0..12).unwrap_or_default().trim());
+
+            let perihelion_distance = column(30..39, "perihelion distance")?;
+            let eccentricity = column(41..49, "eccentricity")?;
+            if !(0.0..1.0).contains(&eccentricity) {
+                catalog
+                    .unbound
+                    .push((number, name.to_string(), eccentricity));
+                continue;
+            }
+
+            catalog.bodies.push(OrbitalElements {
+                name: name.to_string(),
+                epoch,
+                semimajor: perihelion_distance / (1.0 - eccentricity),
+                eccentricity,
+                inclination: column(71..79, "inclination")?,
+                ascending_node: column(61..69, "ascending node")?,
+                argument_of_perihelion: column(51..59, "argument of perihelion")?,
+                perihelion_time,
+                rotational_period: EARTH_ROTATIONAL_PERIOD,
+            });
+        }
+
+        Ok(catalog)
+    }
+}
+
+/// The julian date (TT) at the start of a calendar day, plus its fraction
+fn calendar(year: i32, month: u32, day: f64) -> Option<f64> {
+    let date = NaiveDate::from_ymd_opt(year, month, day.trunc() as u32)?;
+    let unix = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+
+    Some(JULIAN_DAY_UNIX_EPOCH_DAYS + (date - unix).num_days() as f64 + day.fract())
+}
+
+/// Unpacks an epoch such as `K2555` (2025 May 5)
+fn unpack_epoch(packed: &str) -> Option<f64> {
+    let digit = |c: char| match c {
+        '1'..='9' => c.to_digit(10),
+        'A'..='V' => Some(c as u32 - 'A' as u32 + 10),
+        _ => None,
+    };
+
+    let century = match packed.chars().next()? {
+        c @ 'I'..='L' => (c as i32 - 'I' as i32 + 18) * 100,
+        _ => return None,
+    };
+    let year = century + packed.get(1..3)?.parse::<i32>().ok()?;
+    let month = digit(packed.chars().nth(3)?)?;
+    let day = digit(packed.chars().nth(4)?)?;
+
+    calendar(year, month, day as f64)
+}
+
+/// Unpacks an epoch such as `20250505`
+fn unpack_date(packed: &str) -> Option<f64> {
+    calendar(
+        packed.get(0..4)?.parse().ok()?,
+        packed.get(4..6)?.parse().ok()?,
+        packed.get(6..8)?.parse().ok()?,
+    )
+}
@@ -0,0 +1,532 @@
+use displaydoc::Display;
+use strum::AsRefStr;
+use thiserror::Error;
+
+use crate::{
+    conversions::{ASTRONOMICAL_UNIT, GRAVITATIONAL_CONSTANT},
+    ephemeris::{EphemerisBody, Tabulated},
+    kepler::Body,
+    orbit::{
+        add, cross, dot, norm, scale, subtract, OrbitalElements, Perihelion, StateVector, Vector,
+    },
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// Dormand–Prince tableau, the last row is also the fifth order solution
+const TABLEAU: [[f64; 6]; 6] = [
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+
+/// The difference between the fifth and fourth order weights
+const ERRORS: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// The smallest step in seconds the adaptive integrator shrinks to before giving up
+const SMALLEST_STEP: f64 = 1e-6;
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Error)]
+/// This is the collection of problems when integrating a system
+pub enum NbodyError {
+    /// the step of {0} seconds must be a positive number
+    Step(f64),
+    /// the tolerance of {0} must be a positive number for the adaptive integrator
+    Tolerance(f64),
+    /// there is no particle {0}, the system has {1}
+    Particle(usize, usize),
+    /// particle {0} cannot orbit itself
+    Host(usize),
+    /// the interval of {0} days must be a positive number
+    Interval(f64),
+    /// the julian date {0} is not a finite number
+    Date(f64),
+    /// the integration broke down at julian date {0}, particles may have collided
+    Diverged(f64),
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure is a point mass of a system.
+pub struct Particle {
+    /// ### The name of the body
+    pub name: String,
+    /// ### The mass in kilograms
+    pub mass: f64,
+    /// ### The position in kilometers
+    pub position: Vector,
+    /// ### The velocity in kilometers per second
+    pub velocity: Vector,
+}
+
+impl Particle {
+    /// This method gets the gravitational parameter (GM) in cubic kilometers per second squared.
+    pub fn gravitational_parameter(&self) -> f64 {
+        GRAVITATIONAL_CONSTANT * self.mass
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, AsRefStr)]
+/// This is the collection of integrators a system can step with
+pub enum Integrator {
+    /// Kick-drift-kick with a fixed step, conserves energy over long ranges.
+    #[default]
+    #[strum(serialize = "Leapfrog")]
+    Leapfrog,
+    /// Dormand–Prince (RK45) with a step that adapts to the tolerance.
+    #[strum(serialize = "Adaptive")]
+    Adaptive,
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains particles moving under their mutual gravity.
+///
+/// ```rust
+/// use rust_solar::conversions::{GRAVITATIONAL_CONSTANT, SOLAR_GRAVITATIONAL_PARAMETER};
+/// use rust_solar::nbody::{Integrator, Particle, System};
+///
+/// let mut system = System {
+///     particles: vec![
+///         Particle { name: "Sun".to_string(), mass: SOLAR_GRAVITATIONAL_PARAMETER / GRAVITATIONAL_CONSTANT, ..Default::default() },
+///         Particle { name: "Planet".to_string(), mass: 6e24, position: (1.496e8, 0.0, 0.0), velocity: (0.0, 29.78, 0.0) },
+///     ],
+///     julian_date: 2451545.0,
+///     integrator: Integrator::Leapfrog,
+///     step: 3600.0,
+///     tolerance: 0.0,
+/// };
+///
+/// let energy = system.energy();
+/// system.advance(2451545.0 + 365.25).unwrap();
+///
+/// assert!(((system.energy() - energy) / energy).abs() < 1e-6);
+/// ```
+pub struct System {
+    /// ### The particles of the system
+    pub particles: Vec<Particle>,
+    /// ### The julian date (TT) of the particles' states
+    pub julian_date: f64,
+    /// ### The integrator to step with
+    pub integrator: Integrator,
+    /// ### The step in seconds, the first guess for the adaptive integrator
+    pub step: f64,
+    /// ### The relative error allowed per step by the adaptive integrator
+    pub tolerance: f64,
+}
+
+impl System {
+    /// This method calculates the acceleration (km/s²) of every particle at the positions.
+    pub fn accelerations(&self, positions: &[Vector]) -> Vec<Vector> {
+        let mut accelerations = vec![(0.0, 0.0, 0.0); positions.len()];
+
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let between = subtract(positions[j], positions[i]);
+                let cubed = norm(between).powi(3);
+
+                accelerations[i] = add(
+                    accelerations[i],
+                    scale(between, self.particles[j].gravitational_parameter() / cubed),
+                );
+                accelerations[j] = add(
+                    accelerations[j],
+                    scale(
+                        between,
+                        -self.particles[i].gravitational_parameter() / cubed,
+                    ),
+                );
+            }
+        }
+
+        accelerations
+    }
+
+    /// This method calculates the total (kinetic and potential) energy in kg·km²/s².
+    pub fn energy(&self) -> f64 {
+        let mut energy = 0.0;
+
+        for (i, particle) in self.particles.iter().enumerate() {
+            energy += 0.5 * particle.mass * dot(particle.velocity, particle.velocity);
+
+            for other in &self.particles[(i + 1)..] {
+                energy -= particle.gravitational_parameter() * other.mass
+                    / norm(subtract(other.position, particle.position));
+            }
+        }
+
+        energy
+    }
+
+    /// This method moves the system forwards or backwards to a julian date.
+    pub fn advance(&mut self, julian_date: f64) -> Result<(), NbodyError> {
+        if !julian_date.is_finite() {
+            return Err(NbodyError::Date(julian_date));
+        }
+
+        if !(self.step.is_finite() && self.step != 0.0) {
+            return Err(NbodyError::Step(self.step));
+        }
+
+        let adaptive = matches!(self.integrator, Integrator::Adaptive);
+        if adaptive && !(self.tolerance.is_finite() && self.tolerance > 0.0) {
+            return Err(NbodyError::Tolerance(self.tolerance));
+        }
+
+        let mut remaining = (julian_date - self.julian_date) * EARTH_ROTATIONAL_PERIOD;
+        let mut step = self.step.abs().copysign(remaining);
+
+        while remaining.abs() > 1e-9 {
+            if step.abs() > remaining.abs() {
+                step = remaining;
+            }
+
+            let taken = match self.integrator {
+                Integrator::Leapfrog => {
+                    self.leapfrog(step);
+                    step
+                }
+                Integrator::Adaptive => {
+                    let (taken, next) = self.adaptive(step)?;
+                    step = next;
+                    taken
+                }
+            };
+
+            remaining -= taken;
+            self.julian_date += taken / EARTH_ROTATIONAL_PERIOD;
+
+            let finite = |v: Vector| v.0.is_finite() && v.1.is_finite() && v.2.is_finite();
+            if !self
+                .particles
+                .iter()
+                .all(|p| finite(p.position) && finite(p.velocity))
+            {
+                return Err(NbodyError::Diverged(self.julian_date));
+            }
+        }
+
+        self.julian_date = julian_date;
+
+        Ok(())
+    }
+
+    /// This method records the state of a particle around its host until a julian date.
+    pub fn track(
+        &mut self,
+        body: usize,
+        host: usize,
+        end: f64,
+        interval: f64,
+    ) -> Result<Tabulated, NbodyError> {
+        self.pair(body, host)?;
+
+        if !end.is_finite() {
+            return Err(NbodyError::Date(end));
+        }
+
+        if !(interval.is_finite() && interval > 0.0) {
+            return Err(NbodyError::Interval(interval));
+        }
+
+        let mut rows = vec![];
+
+        loop {
+            let (particle, origin) = (&self.particles[body], &self.particles[host]);
+            let position = subtract(particle.position, origin.position);
+
+            rows.push((
+                self.julian_date,
+                StateVector {
+                    position,
+                    velocity: subtract(particle.velocity, origin.velocity),
+                    distance: norm(position),
+                },
+            ));
+
+            if self.julian_date >= end {
+                break;
+            }
+
+            self.advance((self.julian_date + interval).min(end))?;
+        }
+
+        Ok(Tabulated { rows })
+    }
+
+    /// This method integrates a particle around its host into a body with a calendar.
+    ///
+    /// The year is the mean sidereal period measured over the integration,
+    /// so it includes the perturbations of every other particle.
+    pub fn orbit(
+        &mut self,
+        body: usize,
+        host: usize,
+        end: f64,
+        interval: f64,
+        rotational_period: f64,
+        pole: (f64, f64),
+    ) -> Result<EphemerisBody<Orbit, Tabulated>, NbodyError> {
+        self.pair(body, host)?;
+
+        let gravitational_parameter = self.particles[body].gravitational_parameter()
+            + self.particles[host].gravitational_parameter();
+        let track = self.track(body, host, end, interval)?;
+        let (epoch, first) = track.rows[0];
+
+        let mut elements = OrbitalElements::from_state(
+            self.particles[body].name.clone(),
+            epoch,
+            &first,
+            gravitational_parameter,
+        );
+        elements.rotational_period = rotational_period;
+
+        // two-body period for when the track is too short to measure
+        let mut period = 2.0
+            * std::f64::consts::PI
+            * ((elements.semimajor * ASTRONOMICAL_UNIT).powi(3) / gravitational_parameter).sqrt()
+            / EARTH_ROTATIONAL_PERIOD;
+
+        let momentum = cross(first.position, first.velocity);
+        let swept: f64 = track
+            .rows
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (pair[0].1.position, pair[1].1.position);
+                (dot(cross(from, to), momentum) / norm(momentum)).atan2(dot(from, to))
+            })
+            .sum();
+        let elapsed = track.rows[track.rows.len() - 1].0 - epoch;
+
+        if swept.to_degrees() >= 360.0 {
+            period = elapsed * 2.0 * std::f64::consts::PI / swept;
+        }
+
+        Ok(EphemerisBody {
+            body: Orbit {
+                elements,
+                period,
+                pole,
+            },
+            ephemeris: track,
+        })
+    }
+
+    fn positions(&self) -> Vec<Vector> {
+        self.particles.iter().map(|p| p.position).collect()
+    }
+
+    fn leapfrog(&mut self, step: f64) {
+        let kick = |system: &mut Self| {
+            let accelerations = system.accelerations(&system.positions());
+
+            for (particle, acceleration) in system.particles.iter_mut().zip(accelerations) {
+                particle.velocity = add(particle.velocity, scale(acceleration, step / 2.0));
+            }
+        };
+
+        kick(self);
+
+        for particle in self.particles.iter_mut() {
+            particle.position = add(particle.position, scale(particle.velocity, step));
+        }
+
+        kick(self);
+    }
+
+    /// Checks that the body and its host are two particles of the system
+    fn pair(&self, body: usize, host: usize) -> Result<(), NbodyError> {
+        let count = self.particles.len();
+
+        match (body, host) {
+            (body, _) if body >= count => Err(NbodyError::Particle(body, count)),
+            (_, host) if host >= count => Err(NbodyError::Particle(host, count)),
+            (body, host) if body == host => Err(NbodyError::Host(body)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Tries a step and returns the (step taken, next step to try)
+    fn adaptive(&mut self, mut step: f64) -> Result<(f64, f64), NbodyError> {
+        let initial: Vec<(Vector, Vector)> = self
+            .particles
+            .iter()
+            .map(|p| (p.position, p.velocity))
+            .collect();
+
+        loop {
+            let mut stages: Vec<Vec<(Vector, Vector)>> = vec![self.derivatives(&initial)];
+
+            for row in TABLEAU {
+                let state = self.blend(&initial, &stages, &row, step);
+                stages.push(self.derivatives(&state));
+            }
+
+            let solution = self.blend(&initial, &stages, &TABLEAU[5], step);
+            let error = self.blend(
+                &vec![((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)); initial.len()],
+                &stages,
+                &ERRORS,
+                step,
+            );
+
+            // the largest error relative to the size of each vector
+            let ratio = error
+                .iter()
+                .zip(&solution)
+                .map(|(error, state)| {
+                    (norm(error.0) / norm(state.0).max(1.0))
+                        .max(norm(error.1) / norm(state.1).max(1e-9))
+                })
+                .fold(0.0, f64::max)
+                / self.tolerance.max(f64::EPSILON);
+
+            // a NaN error never shrinks, and a vanishing step never arrives
+            if !ratio.is_finite() || step.abs() < SMALLEST_STEP {
+                return Err(NbodyError::Diverged(self.julian_date));
+            }
+
+            let factor = (0.9 * ratio.powf(-0.2)).clamp(0.2, 5.0);
+
+            if ratio <= 1.0 {
+                for (particle, state) in self.particles.iter_mut().zip(solution) {
+                    particle.position = state.0;
+                    particle.velocity = state.1;
+                }
+
+                return Ok((step, step * factor));
+            }
+
+            step *= factor;
+        }
+    }
+
+    fn derivatives(&self, state: &[(Vector, Vector)]) -> Vec<(Vector, Vector)> {
+        let positions: Vec<Vector> = state.iter().map(|s| s.0).collect();
+
+        state
+            .iter()
+            .zip(self.accelerations(&positions))
+            .map(|(s, acceleration)| (s.1, acceleration))
+            .collect()
+    }
+
+    fn blend(
+        &self,
+        initial: &[(Vector, Vector)],
+        stages: &[Vec<(Vector, Vector)>],
+        weights: &[f64],
+        step: f64,
+    ) -> Vec<(Vector, Vector)> {
+        initial
+            .iter()
+            .enumerate()
+            .map(|(i, start)| {
+                stages
+                    .iter()
+                    .zip(weights)
+                    .fold(*start, |state, (stage, weight)| {
+                        (
+                            add(state.0, scale(stage[i].0, step * weight)),
+                            add(state.1, scale(stage[i].1, step * weight)),
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This data structure is the calendar of an integrated orbit.
+///
+/// Pair it with the integrated track through [`System::orbit`].
+pub struct Orbit {
+    /// ### The osculating elements at the start of the integration
+    pub elements: OrbitalElements,
+    /// ### The mean sidereal period in earth days
+    pub period: f64,
+    /// ### The (right ascension, declination) in degrees of the north pole
+    pub pole: (f64, f64),
+}
+
+impl Body for Orbit {
+    fn epoch(&self) -> f64 {
+        self.elements.epoch
+    }
+
+    fn name(&self) -> String {
+        self.elements.name.clone()
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        self.elements.eccentricity
+    }
+
+    fn orbital_period(&self) -> f64 {
+        self.period * EARTH_ROTATIONAL_PERIOD / self.elements.rotational_period
+    }
+
+    fn rotational_period(&self) -> f64 {
+        self.elements.rotational_period
+    }
+
+    /// The elements' perihelion, with months of the measured year
+    fn perihelion(&self) -> Perihelion {
+        let peri = self.elements.perihelion();
+
+        Perihelion {
+            month: (peri.month.0, peri.month.0 + self.orbital_period() / 12.0),
+            ..peri
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        self.elements.semimajor
+    }
+
+    fn inclination(&self) -> f64 {
+        self.elements.inclination
+    }
+
+    fn ascending_node(&self) -> f64 {
+        self.elements.ascending_node
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        self.elements.argument_of_perihelion
+    }
+
+    fn pole(&self) -> (f64, f64) {
+        self.pole
+    }
+}
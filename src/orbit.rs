@@ -1,4 +1,9 @@
-use crate::{anomaly::Anomaly, conversions::radians_in_circle};
+use crate::{
+    anomaly::Anomaly,
+    conversions::{radians_in_circle, ASTRONOMICAL_UNIT},
+    kepler::Body,
+    planets::{EARTH_OBLIQUITY, EARTH_ORBITAL_PERIOD, EARTH_ROTATIONAL_PERIOD},
+};
 use strum::AsRefStr;
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -23,9 +28,9 @@ impl Type {
     /// Gives the shape of the keplerian body based of orbital shpae deviation
     pub fn shape(&self, obe: f64) -> Self {
         match obe {
-            e if e == 0.0 => Self::Circular,
+            0.0 => Self::Circular,
             e if e > 0.0 && e < 1.0 => Self::Elliptical,
-            e if e == 1.0 => Self::Parabolic,
+            1.0 => Self::Parabolic,
             e if e > 1.0 => Self::Hyperbolic,
             e if e == f64::INFINITY => Self::Straight,
             _ => Self::Unknown,
@@ -209,3 +214,345 @@ impl MeanMotion {
         radians_in_circle() * (elapse - elapse.round())
     }
 }
+
+/// A cartesian (x, y, z) vector
+pub type Vector = (f64, f64, f64);
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains the heliocentric state of a body in the ecliptic J2000 frame.
+pub struct StateVector {
+    /// ### (x, y, z) in kilometers
+    pub position: Vector,
+    /// ### (x, y, z) in kilometers per second
+    pub velocity: Vector,
+    /// ### The distance from the sun in kilometers
+    pub distance: f64,
+}
+
+impl StateVector {
+    /// This method computes the state vector from where the body is on its orbit.
+    ///
+    /// * `orbital_period` is in seconds
+    /// * `orientation` is (inclination, ascending node, argument of perihelion) in degrees
+    ///
+    /// > - ![Orbit Equation](https://latex.codecogs.com/svg.image?r=\frac{a(1-e^2)}{1&plus;e\cos\nu})
+    /// > - ![Perifocal Velocity](https://latex.codecogs.com/svg.image?v=\frac{na}{\sqrt{1-e^2}}(-\sin\nu,e&plus;\cos\nu))
+    ///
+    pub fn compute(
+        &self,
+        true_anomaly: f64,
+        orbital_eccentricity: f64,
+        major_axis: f64,
+        orbital_period: f64,
+        orientation: (f64, f64, f64),
+    ) -> Self {
+        let major_axis = major_axis * ASTRONOMICAL_UNIT;
+
+        // Semi-Latus Rectum ( semi-major-axis * (1.0 - eccentricity^2))
+        let p = major_axis * (1.0 - orbital_eccentricity.powf(2.0));
+        let distance = p / (1.0 + orbital_eccentricity * true_anomaly.cos());
+
+        // sqrt(mu / p) where mu = n^2 * a^3
+        let n = radians_in_circle() / orbital_period;
+        let speed = n * major_axis * (major_axis / p).sqrt();
+
+        // position and velocity within the plane of the orbit
+        let (sin_v, cos_v) = true_anomaly.sin_cos();
+        let plane = (distance * cos_v, distance * sin_v);
+        let plane_velocity = (-speed * sin_v, speed * (orbital_eccentricity + cos_v));
+
+        let (sin_i, cos_i) = orientation.0.to_radians().sin_cos();
+        let (sin_o, cos_o) = orientation.1.to_radians().sin_cos();
+        let (sin_w, cos_w) = orientation.2.to_radians().sin_cos();
+
+        // rotates the orbital plane into the ecliptic
+        let rotate = |x: f64, y: f64| {
+            (
+                (cos_o * cos_w - sin_o * sin_w * cos_i) * x
+                    - (cos_o * sin_w + sin_o * cos_w * cos_i) * y,
+                (sin_o * cos_w + cos_o * sin_w * cos_i) * x
+                    + (cos_o * cos_w * cos_i - sin_o * sin_w) * y,
+                (sin_w * sin_i) * x + (cos_w * sin_i) * y,
+            )
+        };
+
+        Self {
+            position: rotate(plane.0, plane.1),
+            velocity: rotate(plane_velocity.0, plane_velocity.1),
+            distance,
+        }
+    }
+
+    /// The magnitude of the velocity in kilometers per second
+    pub fn speed(&self) -> f64 {
+        let (x, y, z) = self.velocity;
+
+        (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt()
+    }
+
+    /// The straight line distance in kilometers between two positions
+    pub fn distance_to(&self, other: &StateVector) -> f64 {
+        let (x, y, z) = self.position;
+        let (ox, oy, oz) = other.position;
+
+        ((x - ox).powf(2.0) + (y - oy).powf(2.0) + (z - oz).powf(2.0)).sqrt()
+    }
+
+    /// The solar longitude (Ls) in degrees, measured along the orbit from the body's vernal equinox.
+    ///
+    /// * `pole` is the (right ascension, declination) in degrees of the body's north pole (equatorial J2000)
+    pub fn solar_longitude(&self, pole: (f64, f64)) -> f64 {
+        let (sin_a, cos_a) = pole.0.to_radians().sin_cos();
+        let (sin_d, cos_d) = pole.1.to_radians().sin_cos();
+        let (sin_e, cos_e) = EARTH_OBLIQUITY.to_radians().sin_cos();
+
+        // the pole rotated from the equator into the ecliptic
+        let axis = (
+            cos_d * cos_a,
+            cos_d * sin_a * cos_e + sin_d * sin_e,
+            sin_d * cos_e - cos_d * sin_a * sin_e,
+        );
+        let normal = cross(self.position, self.velocity);
+        let normal = scale(normal, 1.0 / norm(normal));
+
+        // the body sits here when the sun crosses its equator going north
+        let equinox = cross(normal, axis);
+        let equinox = scale(equinox, 1.0 / norm(equinox));
+        let across = cross(normal, equinox);
+
+        dot(self.position, across)
+            .atan2(dot(self.position, equinox))
+            .to_degrees()
+            .rem_euclid(360.0)
+    }
+
+    /// The heliocentric ecliptic longitude of the position in degrees
+    pub fn longitude(&self) -> f64 {
+        self.position
+            .1
+            .atan2(self.position.0)
+            .to_degrees()
+            .rem_euclid(360.0)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains the keplerian elements of a body loaded at runtime.
+///
+/// The solar longitude is the true longitude along the orbit, split into 12 months of 30°,
+/// and days are `rotational_period` seconds long.
+pub struct OrbitalElements {
+    /// ### The name or designation of the body
+    pub name: String,
+    /// ### The julian date (TT) the elements are for
+    pub epoch: f64,
+    /// ### The semi-major axis in AU (negative for hyperbolic orbits)
+    pub semimajor: f64,
+    /// ### The deviation of the orbit from a circle
+    pub eccentricity: f64,
+    /// ### The tilt against the ecliptic J2000 in degrees
+    pub inclination: f64,
+    /// ### The longitude of the ascending node in degrees
+    pub ascending_node: f64,
+    /// ### The argument of perihelion in degrees
+    pub argument_of_perihelion: f64,
+    /// ### The julian date (TT) the body passes perihelion
+    pub perihelion_time: f64,
+    /// ### The seconds in a day of the body, earth days are used when unknown
+    pub rotational_period: f64,
+}
+
+impl OrbitalElements {
+    /// This method builds the elements from a mean anomaly (degrees) at the epoch.
+    pub fn from_mean_anomaly(
+        name: String,
+        epoch: f64,
+        semimajor: f64,
+        eccentricity: f64,
+        orientation: (f64, f64, f64),
+        mean_anomaly: f64,
+    ) -> Self {
+        let period = EARTH_ORBITAL_PERIOD * semimajor.powf(1.5);
+
+        Self {
+            name,
+            epoch,
+            semimajor,
+            eccentricity,
+            inclination: orientation.0,
+            ascending_node: orientation.1,
+            argument_of_perihelion: orientation.2,
+            // the next perihelion after the epoch
+            perihelion_time: epoch + (360.0 - mean_anomaly.rem_euclid(360.0)) / 360.0 * period,
+            rotational_period: EARTH_ROTATIONAL_PERIOD,
+        }
+    }
+
+    /// This method builds the osculating elements of a state (km, km/s) around a host.
+    ///
+    /// `gravitational_parameter` is the GM (km³/s²) of the host and body together.
+    pub fn from_state(
+        name: String,
+        epoch: f64,
+        state: &StateVector,
+        gravitational_parameter: f64,
+    ) -> Self {
+        let (position, velocity) = (state.position, state.velocity);
+        let radius = norm(position);
+        let momentum = cross(position, velocity);
+        let node = cross((0.0, 0.0, 1.0), momentum);
+        let apsis = scale(
+            subtract(
+                scale(position, dot(velocity, velocity) - gravitational_parameter / radius),
+                scale(velocity, dot(position, velocity)),
+            ),
+            1.0 / gravitational_parameter,
+        );
+
+        let eccentricity = norm(apsis);
+        let energy = dot(velocity, velocity) / 2.0 - gravitational_parameter / radius;
+        let semimajor = -gravitational_parameter / (2.0 * energy);
+        let inclination = (momentum.2 / norm(momentum)).acos().to_degrees();
+
+        // equatorial and circular orbits measure from the x axis instead
+        let ascending_node = match norm(node) > 1e-12 * norm(momentum) {
+            true => node.1.atan2(node.0),
+            false => 0.0,
+        };
+        let reference = (ascending_node.cos(), ascending_node.sin(), 0.0);
+        let pericenter = match eccentricity > 1e-10 {
+            true => apsis,
+            false => reference,
+        };
+        // signed angle in the orbit plane
+        let angle = |from: Vector, to: Vector| {
+            (dot(cross(from, to), momentum) / norm(momentum)).atan2(dot(from, to))
+        };
+        let argument_of_perihelion = angle(reference, pericenter);
+        let true_anomaly = angle(pericenter, position);
+
+        let motion = (gravitational_parameter / semimajor.abs().powi(3)).sqrt();
+        let half = (true_anomaly / 2.0).tan();
+        let since = match eccentricity < 1.0 {
+            true => {
+                let e = 2.0 * (((1.0 - eccentricity) / (1.0 + eccentricity)).sqrt() * half).atan();
+                (e - eccentricity * e.sin()).rem_euclid(radians_in_circle()) - radians_in_circle()
+            }
+            false => {
+                let f = 2.0 * (((eccentricity - 1.0) / (eccentricity + 1.0)).sqrt() * half).atanh();
+                eccentricity * f.sinh() - f
+            }
+        };
+
+        Self {
+            name,
+            epoch,
+            semimajor: semimajor / ASTRONOMICAL_UNIT,
+            eccentricity,
+            inclination,
+            ascending_node: ascending_node.to_degrees().rem_euclid(360.0),
+            argument_of_perihelion: argument_of_perihelion.to_degrees().rem_euclid(360.0),
+            // the next perihelion after the epoch for closed orbits
+            perihelion_time: epoch - since / motion / EARTH_ROTATIONAL_PERIOD,
+            rotational_period: EARTH_ROTATIONAL_PERIOD,
+        }
+    }
+
+    /// The longitude of the perihelion in degrees
+    pub fn perihelion_longitude(&self) -> f64 {
+        (self.ascending_node + self.argument_of_perihelion).rem_euclid(360.0)
+    }
+}
+
+impl Body for OrbitalElements {
+    fn epoch(&self) -> f64 {
+        self.epoch
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        self.eccentricity
+    }
+
+    fn orbital_period(&self) -> f64 {
+        EARTH_ORBITAL_PERIOD * self.semimajor.powf(1.5) * EARTH_ROTATIONAL_PERIOD
+            / self.rotational_period
+    }
+
+    fn rotational_period(&self) -> f64 {
+        self.rotational_period
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        // the first month starts at perihelion, so the solar longitude is the true longitude
+        let day = (self.perihelion_time - self.epoch) * EARTH_ROTATIONAL_PERIOD
+            / self.rotational_period;
+        let peri = self.perihelion_longitude();
+
+        Perihelion {
+            month: (day, day + self.orbital_period() / 12.0),
+            ls: (peri, peri + 30.0),
+            perihelion: peri,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        self.semimajor
+    }
+
+    fn inclination(&self) -> f64 {
+        self.inclination
+    }
+
+    fn ascending_node(&self) -> f64 {
+        self.ascending_node
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        self.argument_of_perihelion
+    }
+}
+
+/// The dot product of two vectors
+pub(crate) fn dot(a: Vector, b: Vector) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+/// The cross product of two vectors
+pub(crate) fn cross(a: Vector, b: Vector) -> Vector {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+/// The length of a vector
+pub(crate) fn norm(a: Vector) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// The vector multiplied by a number
+pub(crate) fn scale(a: Vector, by: f64) -> Vector {
+    (a.0 * by, a.1 * by, a.2 * by)
+}
+
+/// The sum of two vectors
+pub(crate) fn add(a: Vector, b: Vector) -> Vector {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+/// The difference between two vectors
+pub(crate) fn subtract(a: Vector, b: Vector) -> Vector {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+/// Rotates an equatorial J2000 vector into the ecliptic
+pub(crate) fn ecliptic(vector: Vector) -> Vector {
+    let (sin_e, cos_e) = EARTH_OBLIQUITY.to_radians().sin_cos();
+
+    (
+        vector.0,
+        vector.1 * cos_e + vector.2 * sin_e,
+        vector.2 * cos_e - vector.1 * sin_e,
+    )
+}
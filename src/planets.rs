@@ -22,3 +22,6 @@ pub const EARTH_ROTATIONAL_PERIOD: f64 = 86400.0;
 
 /// This is the orbital period for earth in days
 pub const EARTH_ORBITAL_PERIOD: f64 = 365.25;
+
+/// This is the tilt of earth's axis against the ecliptic (J2000) in degrees
+pub const EARTH_OBLIQUITY: f64 = 23.4392911;
@@ -1,9 +1,93 @@
-use chrono::{Datelike, Local, NaiveDate, Utc};
+use chrono::{Datelike, Local, NaiveDate, Offset, Timelike, Utc};
+use chrono_tz::Tz;
+use julian_day_converter::JULIAN_DAY_UNIX_EPOCH_DAYS;
 use icu::datetime::input::{DateInput, IsoTimeInput};
 use icu_calendar::{ethiopian::EthiopianEraStyle, types::Era, AsCalendar};
 use std::str::FromStr;
 
-use crate::julian::JD2NOON;
+use crate::{
+    instant::Instant,
+    julian::{terrestrial_offset, universal_offset, JD2NOON},
+    kepler::{Body, Clock},
+    orbit::Perihelion,
+};
+
+use super::{EARTH_OBLIQUITY, EARTH_ORBITAL_PERIOD, EARTH_ROTATIONAL_PERIOD};
+
+#[derive(Debug, Copy, Clone)]
+/// This structure represents the third planet from the sun
+pub struct Earth;
+
+impl Body for Earth {
+    /// A.D 2000 March 20, 07:35:00 (Vernal Equinox)
+    fn epoch(&self) -> f64 {
+        2.451623816e6
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        0.0167
+    }
+
+    fn orbital_period(&self) -> f64 {
+        EARTH_ORBITAL_PERIOD
+    }
+
+    fn rotational_period(&self) -> f64 {
+        EARTH_ROTATIONAL_PERIOD
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        Perihelion {
+            month: (276.0, 306.0),
+            ls: (270.0, 300.0),
+            perihelion: 283.0,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        1.0
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        102.94719
+    }
+
+    fn obliquity(&self) -> f64 {
+        EARTH_OBLIQUITY
+    }
+
+    fn sidereal_period(&self) -> f64 {
+        86_164.090_5
+    }
+
+    /// Greenwich mean sidereal time at J2000
+    fn prime_meridian(&self) -> f64 {
+        280.46061837
+    }
+
+    /// IAU (2000) rates, the precession of the equinoxes
+    fn pole_precession(&self) -> (f64, f64) {
+        (-0.641, -0.557)
+    }
+
+    fn oblateness(&self) -> f64 {
+        0.00108263
+    }
+
+    fn equatorial_radius(&self) -> f64 {
+        6378.137
+    }
+
+    /// Standish (1992) rate of ϖ, the ecliptic node does not drift
+    fn precession(&self) -> (f64, f64) {
+        (0.32327364, 0.0)
+    }
+
+    /// Includes the general precession of the equinoxes
+    fn perihelion_precession(&self) -> f64 {
+        1.71946
+    }
+}
 
 #[derive(Debug, Default, Clone, Copy)]
 /// This is an earth structure to represent an earth date
@@ -19,7 +103,7 @@ pub struct EarthDate {
 impl EarthDate {
     /// Returns the current date now
     pub fn now() -> Self {
-        let now = chrono::Utc::now();
+        let now = Utc::now();
         Self {
             year: now.year(),
             month: now.month(),
@@ -75,7 +159,7 @@ pub struct EarthDateTime {
 impl EarthDateTime {
     /// This method sets a date time given the input
     pub fn set_datetime(input: String) -> Vec<(String, String)> {
-        if let Ok(time) = chrono_tz::Tz::from_str(input.as_str()) {
+        if let Ok(time) = Tz::from_str(input.as_str()) {
             let time = chrono::DateTime::with_timezone(&Utc::now(), &time).format("%Y/%m/%d %r %Z");
             vec![(input, time.to_string())]
         } else {
@@ -104,6 +188,80 @@ impl EarthTimeZones {
     }
 }
 
+/// This is the utc date time of a julian date (TT), held at the ends of the chrono calendar beyond them
+fn universal(julian_date: f64) -> chrono::DateTime<Utc> {
+    let seconds = (julian_date - JULIAN_DAY_UNIX_EPOCH_DAYS) * EARTH_ROTATIONAL_PERIOD;
+    let seconds = seconds - universal_offset(seconds);
+    let nanos = (seconds.rem_euclid(1.0) * 1e9).min(999_999_999.0);
+
+    match chrono::DateTime::from_timestamp(seconds.floor() as i64, nanos as u32) {
+        Some(date_time) => date_time,
+        None if seconds < 0.0 => chrono::DateTime::<Utc>::MIN_UTC,
+        None => chrono::DateTime::<Utc>::MAX_UTC,
+    }
+}
+
+/// The zones of the chrono database, like those in [`EarthTimeZones::all_timezones`], are earth clocks.
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use rust_solar::instant::Instant;
+/// use rust_solar::planets::mars::Martian;
+///
+/// // 14:00 Hellas Time on 2024-07-01 UTC
+/// let hellas = Instant::at(Martian::MTCp2, 14.0, 2460492.5);
+/// let pacific = hellas.with_zone(Tz::US__Pacific).time();
+///
+/// assert_eq!("PDT", pacific.code);
+/// assert_eq!("US/Pacific", pacific.name);
+/// ```
+impl Clock for Tz {
+    fn day_length(&self) -> f64 {
+        1.0
+    }
+
+    fn hours(&self, julian_date: f64) -> f64 {
+        let local = universal(julian_date).with_timezone(self);
+
+        local.hour() as f64
+            + local.minute() as f64 / 60.0
+            + (local.second() as f64 + local.nanosecond() as f64 / 1e9) / 3600.0
+    }
+
+    fn identifier(&self) -> String {
+        self.name().to_string()
+    }
+
+    fn labels(&self, julian_date: f64) -> (String, String, String) {
+        let local = universal(julian_date).with_timezone(self);
+
+        (
+            local.format("%Z").to_string(),
+            self.name().to_string(),
+            format!("UTC{}", local.offset().fix()),
+        )
+    }
+}
+
+impl Instant<Tz> {
+    /// This method creates the moment of a chrono date time.
+    pub fn from_date_time(date_time: &chrono::DateTime<Tz>) -> Self {
+        let seconds =
+            date_time.timestamp() as f64 + date_time.timestamp_subsec_nanos() as f64 / 1e9;
+        let seconds = seconds + terrestrial_offset(seconds);
+
+        Self::new(
+            JULIAN_DAY_UNIX_EPOCH_DAYS + seconds / EARTH_ROTATIONAL_PERIOD,
+            date_time.timezone(),
+        )
+    }
+
+    /// This method gets the moment as a chrono date time in the zone.
+    pub fn date_time(&self) -> chrono::DateTime<Tz> {
+        universal(self.julian_date).with_timezone(&self.zone)
+    }
+}
+
 /** ## This is a declarative macro that abstracts the [`EarthDateTime::set_datetime`] method.
   
     > Takes in a location which returns the datetime + timezone for that location. 
@@ -1 +1,80 @@
+use crate::{kepler::Body, orbit::Perihelion};
 
+#[derive(Debug, Copy, Clone)]
+/// This structure represents the fifth planet from the sun
+pub struct Jupiter;
+
+impl Body for Jupiter {
+    /// A.D 1997 August 12, 12:00:00 (Vernal Equinox)
+    fn epoch(&self) -> f64 {
+        2.450673e6
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        0.0484
+    }
+
+    /// The earth's 4332.59 days in the jovian solar day
+    fn orbital_period(&self) -> f64 {
+        10_476.0
+    }
+
+    /// The solar day of System III, the radio rotation of the magnetic field
+    fn rotational_period(&self) -> f64 {
+        35_733.1
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        Perihelion {
+            month: (812.4, 1605.5),
+            ls: (30.0, 60.0),
+            perihelion: 58.4,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        5.20289
+    }
+
+    fn inclination(&self) -> f64 {
+        1.30440
+    }
+
+    fn ascending_node(&self) -> f64 {
+        100.47391
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        274.25457
+    }
+
+    fn obliquity(&self) -> f64 {
+        3.13
+    }
+
+    fn sidereal_period(&self) -> f64 {
+        35_729.71
+    }
+
+    fn pole(&self) -> (f64, f64) {
+        (268.056595, 64.495303)
+    }
+
+    /// IAU (2009) rates
+    fn pole_precession(&self) -> (f64, f64) {
+        (-0.006499, 0.002413)
+    }
+
+    fn oblateness(&self) -> f64 {
+        0.014736
+    }
+
+    fn equatorial_radius(&self) -> f64 {
+        71_492.0
+    }
+
+    /// Standish (1992) rates of ϖ - Ω and Ω
+    fn precession(&self) -> (f64, f64) {
+        (0.00783562, 0.20469106)
+    }
+}
@@ -1,20 +1,39 @@
 use crate::{
+    format::ParseError,
     julian::JD2NOON,
-    kepler::{Body, Date, DateTime, HourType, Time, TimeZone},
+    kepler::{Body, Clock, Date, DateTime, HourType, Time, TimeZone, ZoneMap},
+    light::{LightError, LightTime},
     orbit::{MeanMotion, Perihelion, SemiAxis},
 };
 
 use chrono::Datelike;
 use julian_day_converter::JULIAN_DAY_UNIX_EPOCH_DAYS;
+use std::str::FromStr;
 use strum::{AsRefStr, EnumProperty, VariantArray};
 
-use super::EARTH_ROTATIONAL_PERIOD;
+use super::{earth::Earth, EARTH_ROTATIONAL_PERIOD};
 
 #[derive(Debug, Copy, Clone)]
 /// This structure represents the fourth planet from the sun
 pub struct Mars;
 
-#[derive(Default, Debug, Copy, Clone, AsRefStr, EnumProperty, VariantArray)]
+/// The earth days in a sol (Allison & McEwen, 2000)
+const SOL: f64 = 1.027_491_251_7;
+
+/// Planetary perturbations of mars (amplitude degrees, period julian years, phase degrees)
+///
+/// > Allison & McEwen (2000), Table 5
+const PERTURBATIONS: [(f64, f64, f64); 7] = [
+    (0.0071, 2.2353, 49.409),
+    (0.0057, 2.7543, 168.173),
+    (0.0039, 1.1177, 191.837),
+    (0.0037, 15.7866, 21.736),
+    (0.0021, 2.1354, 15.704),
+    (0.0020, 2.4694, 95.528),
+    (0.0018, 32.8493, 49.095),
+];
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, AsRefStr, EnumProperty, VariantArray)]
 /// This structure represents the martian timezone
 ///
 /// Offset is in 1 decisol, (-2.5 west, +2.5 east)
@@ -163,6 +182,67 @@ impl Body for Mars {
         SemiAxis(self.semimajor()).minor(self.orbital_eccentricity())
     }
 
+    fn inclination(&self) -> f64 {
+        1.85061
+    }
+
+    fn ascending_node(&self) -> f64 {
+        49.57854
+    }
+
+    fn argument_of_perihelion(&self) -> f64 {
+        286.4623
+    }
+
+    fn obliquity(&self) -> f64 {
+        25.19
+    }
+
+    fn sidereal_period(&self) -> f64 {
+        88_642.663
+    }
+
+    /// The mean sun's right ascension and hour angle at Airy-0 (Allison & McEwen, 2000)
+    fn prime_meridian(&self) -> f64 {
+        313.3848
+    }
+
+    /// Perturbed by the other planets, accurate to about 0.01° in the 20th and 21st centuries
+    fn solar_longitude(&self, julian_date: f64) -> f64 {
+        Mars::solar_longitude(self, julian_date)
+    }
+
+    fn equation_of_time(&self, julian_date: f64) -> f64 {
+        self.allison(julian_date).1
+    }
+
+    fn pole(&self) -> (f64, f64) {
+        (317.68143, 52.8865)
+    }
+
+    /// IAU (2000) rates, the martian equinox circles once in about 170,000 years
+    fn pole_precession(&self) -> (f64, f64) {
+        (-0.1061, -0.0609)
+    }
+
+    fn oblateness(&self) -> f64 {
+        0.00196045
+    }
+
+    fn equatorial_radius(&self) -> f64 {
+        3396.19
+    }
+
+    /// Standish (1992) rates of ϖ - Ω and Ω
+    fn precession(&self) -> (f64, f64) {
+        (0.73698431, -0.29257343)
+    }
+
+    /// Allison & McEwen (2000), includes the precession of the martian equinox
+    fn perihelion_precession(&self) -> f64 {
+        0.64890
+    }
+
     fn mean_motion(&mut self, day: f64) -> f64 {
         MeanMotion::by(
             &mut MeanMotion,
@@ -172,15 +252,219 @@ impl Body for Mars {
         )
     }
 
+    /// The year and sol count from the two-body orbit, the month and season follow Allison's Ls
     fn to_date(&mut self, julian_date: f64) -> Date {
-        Date::default().compute(
-            julian_date,
-            self.epoch(),
-            self.rotational_period(),
-            self.perihelion(),
-            self.semimajor(),
-            self.orbital_eccentricity(),
-            self.orbital_period(),
+        Date::default()
+            .compute(
+                julian_date,
+                self.epoch(),
+                self.rotational_period(),
+                self.perihelion_at(julian_date),
+                self.semimajor(),
+                self.orbital_eccentricity(),
+                self.orbital_period(),
+            )
+            .with_solar_longitude(self.solar_longitude(julian_date), self.perihelion())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, AsRefStr, EnumProperty, VariantArray)]
+/// This structure represents the landing sites whose missions count their own sols
+///
+/// Mission sols start at local mean midnight,
+/// the landing sol is Sol 0 except for Pathfinder and the rovers, which landed on Sol 1.
+///
+/// Longitudes are east, landings are julian dates (TT).
+pub enum Site {
+    #[strum(props(
+        Name = "Chryse Planitia",
+        Mission = "Viking 1",
+        Latitude = "22.27",
+        Longitude = "312.04863",
+        Landing = "2442979.99575",
+        First = "0"
+    ))]
+    /// Viking 1 lander, 1976 July 20
+    Viking1,
+    #[strum(props(
+        Name = "Utopia Planitia",
+        Mission = "Viking 2",
+        Latitude = "47.64",
+        Longitude = "225.71",
+        Landing = "2443025.45772",
+        First = "0"
+    ))]
+    /// Viking 2 lander, 1976 September 3
+    Viking2,
+    #[strum(props(
+        Name = "Ares Vallis",
+        Mission = "Mars Pathfinder",
+        Latitude = "19.13",
+        Longitude = "326.78",
+        Landing = "2450634.20692",
+        First = "1"
+    ))]
+    /// Mars Pathfinder, 1997 July 4
+    Pathfinder,
+    #[strum(props(
+        Name = "Gusev Crater",
+        Mission = "Spirit (MER-A)",
+        Latitude = "-14.57",
+        Longitude = "175.47",
+        Landing = "2453008.69172",
+        First = "1"
+    ))]
+    /// Spirit rover, 2004 January 4
+    Spirit,
+    #[strum(props(
+        Name = "Meridiani Planum",
+        Mission = "Opportunity (MER-B)",
+        Latitude = "-1.95",
+        Longitude = "354.47",
+        Landing = "2453029.71255",
+        First = "1"
+    ))]
+    /// Opportunity rover, 2004 January 25
+    Opportunity,
+    #[strum(props(
+        Name = "Gale Crater",
+        Mission = "Curiosity (MSL)",
+        Latitude = "-4.59",
+        Longitude = "137.44",
+        Landing = "2456145.72158",
+        First = "0"
+    ))]
+    /// Curiosity rover, 2012 August 6
+    Curiosity,
+    #[strum(props(
+        Name = "Jezero Crater",
+        Mission = "Perseverance (M2020)",
+        Latitude = "18.44",
+        Longitude = "77.45",
+        Landing = "2459264.37233",
+        First = "0"
+    ))]
+    /// Perseverance rover, 2021 February 18
+    Perseverance,
+}
+
+impl Site {
+    fn property(&self, name: &str) -> f64 {
+        self.get_str(name)
+            .unwrap()
+            .parse::<f64>()
+            .expect("Site properties to be numbers")
+    }
+
+    /// This method gets the (latitude, east longitude) of the site in degrees.
+    pub fn location(&self) -> (f64, f64) {
+        (self.property("Latitude"), self.property("Longitude"))
+    }
+
+    /// This method gets the julian date (TT) of the landing.
+    pub fn landing(&self) -> f64 {
+        self.property("Landing")
+    }
+
+    /// This method calculates the local mars sol date, which counts sols from the local midnight.
+    fn local_sol_date(&self, julian_date: f64) -> f64 {
+        Mars.sol_date(julian_date) + self.location().1 / 360.0
+    }
+
+    /// This method calculates the mission sol at a julian date (TT), negative before the landing sol.
+    ///
+    /// ```rust
+    /// use rust_solar::planets::mars::Site;
+    ///
+    /// // Opportunity last called home on 2018 June 10
+    /// assert_eq!(5111, Site::Opportunity.sol(2458280.0));
+    /// ```
+    pub fn sol(&self, julian_date: f64) -> i32 {
+        let landing = self.local_sol_date(self.landing()).floor();
+
+        (self.local_sol_date(julian_date).floor() - landing) as i32 + self.property("First") as i32
+    }
+
+    /// This method calculates the julian date (TT) of the local midnight that starts a mission sol.
+    pub fn sol_start(&self, sol: i32) -> f64 {
+        let landing = self.local_sol_date(self.landing()).floor();
+        let local = landing + (sol - self.property("First") as i32) as f64;
+
+        self.landing() + (local - self.local_sol_date(self.landing())) * SOL
+    }
+
+    /// This method calculates the local mean solar time in hours at the site.
+    pub fn lmst(&self, julian_date: f64) -> f64 {
+        Mars.lmst(julian_date, self.location().1)
+    }
+
+    /// This method calculates the local true solar time in hours at the site.
+    pub fn ltst(&self, julian_date: f64) -> f64 {
+        Mars.ltst(julian_date, self.location().1)
+    }
+}
+
+/// A martian zone is read from its code (`HT`), name (`Hellas Time`) or offset (`MTC+2`, `MTCp2`).
+impl FromStr for Martian {
+    type Err = ParseError;
+
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        let label = label.trim();
+
+        Self::VARIANTS
+            .iter()
+            .find(|zone| {
+                let offset = (zone.offset() / 2.5) as i32;
+                let relative = match offset {
+                    0 => "MTC".to_string(),
+                    _ => format!("MTC{offset:+}"),
+                };
+
+                [
+                    zone.get_str("Code").unwrap(),
+                    zone.get_str("Name").unwrap(),
+                    zone.as_ref(),
+                ]
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(label))
+                    || relative.eq_ignore_ascii_case(label)
+            })
+            .copied()
+            .ok_or(ParseError::Unknown(label.to_string(), "zone"))
+    }
+}
+
+impl ZoneMap for Martian {
+    fn zones() -> &'static [Self] {
+        Self::VARIANTS
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let bound = |name| {
+            self.get_str(name)
+                .unwrap()
+                .parse::<f64>()
+                .expect("Bounds to be established")
+        };
+
+        (bound("East"), bound("West"))
+    }
+}
+
+impl Clock for Martian {
+    fn day_length(&self) -> f64 {
+        SOL
+    }
+
+    fn hours(&self, julian_date: f64) -> f64 {
+        Mars.zone_time(julian_date, *self)
+    }
+
+    fn labels(&self, _julian_date: f64) -> (String, String, String) {
+        (
+            self.get_str("Code").unwrap().to_string(),
+            self.get_str("Name").unwrap().to_string(),
+            self.as_ref().to_string(),
         )
     }
 }
@@ -293,4 +577,93 @@ impl Mars {
 
         DateTime { date, time }
     }
+
+    /// This method calculates the coordinated mars time (MTC) in hours, the mean solar time at Airy-0.
+    ///
+    /// The julian date is in terrestrial time (TT).
+    pub fn coordinated_time(&self, julian_date: f64) -> f64 {
+        (24.0 * self.sol_date(julian_date)).rem_euclid(24.0)
+    }
+
+    /// This method calculates the mars sol date (MSD), the sols since 1873 December 29 at Airy-0.
+    ///
+    /// The julian date is in terrestrial time (TT).
+    pub fn sol_date(&self, julian_date: f64) -> f64 {
+        (julian_date - 2451549.5) / SOL + 44796.0 - 0.000_962_6
+    }
+
+    /// This method calculates the time in hours on a martian zone's clock.
+    pub fn zone_time(&self, julian_date: f64, zone: Martian) -> f64 {
+        (self.coordinated_time(julian_date) + zone.offset()).rem_euclid(24.0)
+    }
+
+    /// This method calculates the local mean solar time in hours at an east longitude (degrees).
+    ///
+    /// ```rust
+    /// use rust_solar::planets::mars::Mars;
+    ///
+    /// // Curiosity at Gale Crater (137.4°E) on 2000-01-06 00:00 UTC
+    /// assert!((Mars.lmst(2451549.50074, 137.4) - 9.154).abs() < 1e-3);
+    /// ```
+    pub fn lmst(&self, julian_date: f64, east_longitude: f64) -> f64 {
+        (self.coordinated_time(julian_date) + east_longitude / 15.0).rem_euclid(24.0)
+    }
+
+    /// This method calculates the local true solar time in hours at an east longitude (degrees).
+    ///
+    /// This is the time a sundial would show, the mean time corrected by the equation of time.
+    pub fn ltst(&self, julian_date: f64, east_longitude: f64) -> f64 {
+        (self.lmst(julian_date, east_longitude) + self.equation_of_time(julian_date) / 15.0)
+            .rem_euclid(24.0)
+    }
+
+    /// This method calculates the solar longitude in degrees from the perturbed mean anomaly.
+    ///
+    /// This is the Ls of mars, its dates and [`Body::solar_longitude`] use it,
+    /// an [`EphemerisBody`](crate::ephemeris::EphemerisBody) with a planetary theory agrees within 0.02°.
+    pub fn solar_longitude(&self, julian_date: f64) -> f64 {
+        self.allison(julian_date).0
+    }
+
+    /// This method calculates the (solar longitude, equation of time) in degrees.
+    ///
+    /// > Allison & McEwen (2000), or [Mars24](https://www.giss.nasa.gov/tools/mars24/help/algorithm.html)
+    fn allison(&self, julian_date: f64) -> (f64, f64) {
+        let days = julian_date - JD2NOON;
+        let mean_anomaly = (19.3871 + 0.524_020_73 * days).to_radians();
+        let fictitious_sun = 270.3871 + 0.524_038_496 * days;
+
+        let perturbations: f64 = PERTURBATIONS
+            .iter()
+            .map(|(amplitude, period, phase)| {
+                amplitude * (0.985_626 * days / period + phase).to_radians().cos()
+            })
+            .sum();
+
+        let center = (10.691 + 3.0e-7 * days) * mean_anomaly.sin()
+            + 0.623 * (2.0 * mean_anomaly).sin()
+            + 0.050 * (3.0 * mean_anomaly).sin()
+            + 0.005 * (4.0 * mean_anomaly).sin()
+            + 0.0005 * (5.0 * mean_anomaly).sin()
+            + perturbations;
+
+        let ls = (fictitious_sun + center).rem_euclid(360.0);
+        let radians = ls.to_radians();
+        let equation_of_time = 2.861 * (2.0 * radians).sin() - 0.071 * (4.0 * radians).sin()
+            + 0.002 * (6.0 * radians).sin()
+            - center;
+
+        (ls, equation_of_time)
+    }
+
+    /// This method generates the mars-earth signal delay from `start` to `end` every `step` (earth days)
+    pub fn signal_delays(
+        &self,
+        start: f64,
+        end: f64,
+        step: f64,
+        corrected: bool,
+    ) -> Result<Vec<LightTime>, LightError> {
+        LightTime::default().timeline(&Earth, self, start, end, step, corrected)
+    }
 }
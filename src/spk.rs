@@ -0,0 +1,381 @@
+use std::{fs, path::Path, sync::Arc};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{
+    ephemeris::Ephemeris,
+    julian::JD2NOON,
+    orbit::{add, ecliptic, norm, subtract, StateVector},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The bytes in a DAF record
+const RECORD: usize = 1024;
+
+/// The doubles (ND) and integers (NI) of an SPK segment summary
+const SUMMARY: (usize, usize) = (2, 6);
+
+/// The naif id of the sun
+pub const SUN: i32 = 10;
+
+/// The naif id of the solar system barycenter
+pub const BARYCENTER: i32 = 0;
+
+#[derive(Display, Debug, Error)]
+/// This is the collection of problems when reading a kernel
+pub enum KernelError {
+    /// could not read the kernel: {0}
+    Io(#[from] std::io::Error),
+    /// the file is not a DAF/SPK kernel
+    NotSpk,
+    /// the kernel is cut short at byte {0}
+    Truncated(usize),
+    /// segment type {0} is not supported, only types 2 and 3 are
+    UnsupportedType(i32),
+    /// reference frame {0} is not supported, only J2000 (1) and ECLIPJ2000 (17) are
+    UnsupportedFrame(i32),
+    /// no segment covers body {0} at julian date {1}
+    NotCovered(i32, f64),
+    /// the {0} of the kernel is corrupt
+    Corrupt(&'static str),
+    /// the segments of body {0} lead back to it instead of the barycenter
+    Cycle(i32),
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains where a segment's chebyshev records live in the kernel
+pub struct Segment {
+    /// ### (Start, End) in seconds past J2000 (TDB)
+    pub coverage: (f64, f64),
+    /// ### The naif id of the body
+    pub target: i32,
+    /// ### The naif id of the body this segment is relative to
+    pub center: i32,
+    /// ### The naif id of the reference frame
+    pub frame: i32,
+    /// ### The SPK data type
+    pub data_type: i32,
+    /// ### (Start, End) as 1-based double addresses
+    pub address: (usize, usize),
+}
+
+#[derive(Debug, Clone)]
+/// This structure reads a JPL SPK (`.bsp`) kernel, such as DE440, from disk.
+///
+/// The whole kernel is kept in memory, so trimmed kernels are recommended for big ephemerides.
+pub struct Kernel {
+    /// ### The segments in the order they appear in the kernel
+    pub segments: Vec<Segment>,
+    bytes: Vec<u8>,
+    little_endian: bool,
+}
+
+impl Kernel {
+    /// This method opens the kernel at the path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, KernelError> {
+        Self::from_bytes(fs::read(path)?)
+    }
+
+    /// This method reads a kernel that is already in memory.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, KernelError> {
+        if bytes.len() < RECORD || &bytes[0..7] != b"DAF/SPK" {
+            return Err(KernelError::NotSpk);
+        }
+
+        let mut kernel = Self {
+            segments: Vec::new(),
+            little_endian: &bytes[88..96] != b"BIG-IEEE",
+            bytes,
+        };
+        let mut segments = Vec::new();
+
+        let (nd, ni) = SUMMARY;
+        if (kernel.int(8)?, kernel.int(12)?) != (nd as i32, ni as i32) {
+            return Err(KernelError::NotSpk);
+        }
+
+        let summary_size = (nd + ni.div_ceil(2)) * 8;
+        let mut record = kernel.int(76)?;
+        let mut visited = Vec::new();
+
+        // the summary records are a linked list, ending at record 0
+        while record != 0 {
+            if record < 0 || visited.contains(&record) {
+                return Err(KernelError::Corrupt("summary records"));
+            }
+            visited.push(record);
+
+            let start = (record as usize - 1)
+                .checked_mul(RECORD)
+                .ok_or(KernelError::Corrupt("summary records"))?;
+            let count = kernel.double(start + 16)?;
+
+            if !(0.0..=((RECORD - 24) / summary_size) as f64).contains(&count) {
+                return Err(KernelError::Corrupt("summary records"));
+            }
+
+            for index in 0..count as usize {
+                let summary = start + 24 + index * summary_size;
+                let int = |n: usize| kernel.int(summary + nd * 8 + n * 4);
+                let address = |n: usize| match int(n)? {
+                    address if address > 0 => Ok(address as usize),
+                    _ => Err(KernelError::Corrupt("segment addresses")),
+                };
+
+                segments.push(Segment {
+                    coverage: (kernel.double(summary)?, kernel.double(summary + 8)?),
+                    target: int(0)?,
+                    center: int(1)?,
+                    frame: int(2)?,
+                    data_type: int(3)?,
+                    address: (address(4)?, address(5)?),
+                });
+            }
+
+            record = kernel.double(start)? as i32;
+        }
+
+        kernel.segments = segments;
+
+        Ok(kernel)
+    }
+
+    /// This method computes the state of the target relative to the center of its segment.
+    ///
+    /// The state is in the ecliptic J2000 frame, in kilometers and kilometers per second.
+    pub fn segment_state(
+        &self,
+        segment: &Segment,
+        julian_date: f64,
+    ) -> Result<StateVector, KernelError> {
+        let components = match segment.data_type {
+            2 => 3,
+            3 => 6,
+            other => return Err(KernelError::UnsupportedType(other)),
+        };
+
+        // the directory at the end of the segment
+        let (first, last) = segment.address;
+        if last < first + 3 {
+            return Err(KernelError::Corrupt("segment addresses"));
+        }
+
+        let (init, interval) = (self.word(last - 3)?, self.word(last - 2)?);
+        let (record_size, records) = (self.word(last - 1)?, self.word(last)?);
+        let span = (last - 3 - first) as f64;
+
+        // every record holds the midpoint, the radius and a coefficient per component
+        if !(record_size >= (2 + components) as f64 && records >= 1.0)
+            || record_size * records > span
+        {
+            return Err(KernelError::Corrupt("segment directory"));
+        }
+
+        let (record_size, records) = (record_size as usize, records as usize);
+        let seconds = (julian_date - JD2NOON) * EARTH_ROTATIONAL_PERIOD;
+        let index = (((seconds - init) / interval).floor().max(0.0) as usize).min(records - 1);
+        let start = first + index * record_size;
+
+        let (middle, radius) = (self.word(start)?, self.word(start + 1)?);
+        let degree = (record_size - 2) / components;
+        let t = (seconds - middle) / radius;
+
+        let mut values = [0.0; 6];
+        let mut rates = [0.0; 6];
+        for (component, (value, rate)) in values
+            .iter_mut()
+            .zip(rates.iter_mut())
+            .take(components)
+            .enumerate()
+        {
+            let coefficients = (0..degree)
+                .map(|k| self.word(start + 2 + component * degree + k))
+                .collect::<Result<Vec<f64>, KernelError>>()?;
+
+            (*value, *rate) = chebyshev(&coefficients, t);
+            *rate /= radius;
+        }
+
+        let velocity = match segment.data_type {
+            2 => (rates[0], rates[1], rates[2]),
+            _ => (values[3], values[4], values[5]),
+        };
+        let position = (values[0], values[1], values[2]);
+
+        let (position, velocity) = match segment.frame {
+            1 => (ecliptic(position), ecliptic(velocity)),
+            17 => (position, velocity),
+            other => return Err(KernelError::UnsupportedFrame(other)),
+        };
+
+        Ok(StateVector {
+            position,
+            velocity,
+            distance: norm(position),
+        })
+    }
+
+    /// This method computes the state of the target relative to the solar system barycenter.
+    pub fn barycentric_state(
+        &self,
+        target: i32,
+        julian_date: f64,
+    ) -> Result<StateVector, KernelError> {
+        let seconds = (julian_date - JD2NOON) * EARTH_ROTATIONAL_PERIOD;
+        let mut state = StateVector::default();
+        let mut body = target;
+        let mut visited = Vec::new();
+
+        // chains the segments down to the barycenter, earth -> earth-moon barycenter -> barycenter
+        while body != BARYCENTER {
+            if visited.contains(&body) {
+                return Err(KernelError::Cycle(target));
+            }
+            visited.push(body);
+
+            let segment = self
+                .segments
+                .iter()
+                .rev()
+                .find(|segment| {
+                    segment.target == body
+                        && segment.coverage.0 <= seconds
+                        && seconds <= segment.coverage.1
+                })
+                .ok_or(KernelError::NotCovered(body, julian_date))?;
+            let link = self.segment_state(segment, julian_date)?;
+
+            state.position = add(state.position, link.position);
+            state.velocity = add(state.velocity, link.velocity);
+            body = segment.center;
+        }
+
+        Ok(state)
+    }
+
+    /// This method computes the state of the target relative to the sun.
+    pub fn heliocentric_state(
+        &self,
+        target: i32,
+        julian_date: f64,
+    ) -> Result<StateVector, KernelError> {
+        let body = self.barycentric_state(target, julian_date)?;
+        let sun = self.barycentric_state(SUN, julian_date)?;
+        let position = subtract(body.position, sun.position);
+
+        Ok(StateVector {
+            position,
+            velocity: subtract(body.velocity, sun.velocity),
+            distance: norm(position),
+        })
+    }
+
+    /// This method makes an ephemeris for one body of the kernel, checking it's covered at J2000.
+    pub fn ephemeris(self: &Arc<Self>, target: i32) -> Result<SpkEphemeris, KernelError> {
+        self.heliocentric_state(target, JD2NOON)?;
+
+        Ok(SpkEphemeris {
+            kernel: Arc::clone(self),
+            target,
+        })
+    }
+
+    /// Reads the double at the 1-based address
+    fn word(&self, address: usize) -> Result<f64, KernelError> {
+        let offset = address
+            .checked_sub(1)
+            .and_then(|index| index.checked_mul(8))
+            .ok_or(KernelError::Corrupt("segment addresses"))?;
+
+        self.double(offset)
+    }
+
+    /// Reads the double at the byte offset
+    fn double(&self, offset: usize) -> Result<f64, KernelError> {
+        let bytes: [u8; 8] = self
+            .bytes
+            .get(offset..offset.saturating_add(8))
+            .ok_or(KernelError::Truncated(offset))?
+            .try_into()
+            .expect("slice to be 8 bytes");
+
+        Ok(match self.little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Reads the integer at the byte offset
+    fn int(&self, offset: usize) -> Result<i32, KernelError> {
+        let bytes: [u8; 4] = self
+            .bytes
+            .get(offset..offset.saturating_add(4))
+            .ok_or(KernelError::Truncated(offset))?
+            .try_into()
+            .expect("slice to be 4 bytes");
+
+        Ok(match self.little_endian {
+            true => i32::from_le_bytes(bytes),
+            false => i32::from_be_bytes(bytes),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This structure is an ephemeris provider for one body of a kernel.
+///
+/// ## Limitations
+/// Outside of what the kernel covers the [`Ephemeris`] state is NaN,
+/// [`SpkEphemeris::state_at`] returns the error instead.
+pub struct SpkEphemeris {
+    /// ### The kernel shared between bodies
+    pub kernel: Arc<Kernel>,
+    /// ### The naif id of the body (499 is mars, 4 is the mars barycenter)
+    pub target: i32,
+}
+
+impl SpkEphemeris {
+    /// This method computes the heliocentric state, failing outside of what the kernel covers.
+    pub fn state_at(&self, julian_date: f64) -> Result<StateVector, KernelError> {
+        self.kernel.heliocentric_state(self.target, julian_date)
+    }
+}
+
+impl Ephemeris for SpkEphemeris {
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        SpkEphemeris::state_at(self, julian_date).unwrap_or(StateVector {
+            position: (f64::NAN, f64::NAN, f64::NAN),
+            velocity: (f64::NAN, f64::NAN, f64::NAN),
+            distance: f64::NAN,
+        })
+    }
+}
+
+/// Evaluates a chebyshev series and its derivative at t (-1 to 1)
+fn chebyshev(coefficients: &[f64], t: f64) -> (f64, f64) {
+    let (mut value, mut rate) = (0.0, 0.0);
+    let (mut previous, mut current) = (1.0, t);
+    let (mut previous_rate, mut current_rate) = (0.0, 1.0);
+
+    for (k, coefficient) in coefficients.iter().enumerate() {
+        let (polynomial, derivative) = match k {
+            0 => (1.0, 0.0),
+            1 => (t, 1.0),
+            _ => {
+                let next = 2.0 * t * current - previous;
+                let next_rate = 2.0 * current + 2.0 * t * current_rate - previous_rate;
+
+                (previous, current) = (current, next);
+                (previous_rate, current_rate) = (current_rate, next_rate);
+
+                (next, next_rate)
+            }
+        };
+
+        value += coefficient * polynomial;
+        rate += coefficient * derivative;
+    }
+
+    (value, rate)
+}
@@ -0,0 +1,391 @@
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::{
+    conversions::{radians_in_circle, ASTRONOMICAL_UNIT, SOLAR_GRAVITATIONAL_PARAMETER},
+    events::Events,
+    julian::Julian,
+    kepler::{Body, Date},
+    orbit::{cross, dot, norm, scale, subtract, Vector},
+    planets::{earth::EarthDateTime, EARTH_ROTATIONAL_PERIOD},
+};
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains a hohmann transfer between the orbits of two bodies.
+///
+/// Both orbits are treated as circles with the radius of their semi-major axis.
+pub struct Hohmann {
+    /// ### The burn in kilometers per second to leave the departure orbit
+    pub departure_delta_v: f64,
+    /// ### The burn in kilometers per second to join the arrival orbit
+    pub arrival_delta_v: f64,
+    /// ### The time in earth days from departure to arrival
+    pub transfer_time: f64,
+    /// ### The time in earth days between launch windows
+    pub synodic_period: f64,
+    /// ### The angle in degrees the destination leads the departure body by at launch
+    pub phase_angle: f64,
+}
+
+impl Hohmann {
+    /// This method computes the hohmann transfer from one body to another.
+    ///
+    /// Both bodies are taken to orbit the sun, with their semi-major axes around it.
+    ///
+    /// > - ![Departure](https://latex.codecogs.com/svg.image?\Delta&space;v_1=\sqrt{\frac{\mu}{r_1}}\left(\sqrt{\frac{2r_2}{r_1&plus;r_2}}-1\right))
+    /// > - ![Arrival](https://latex.codecogs.com/svg.image?\Delta&space;v_2=\sqrt{\frac{\mu}{r_2}}\left(1-\sqrt{\frac{2r_1}{r_1&plus;r_2}}\right))
+    /// > - ![Transfer Time](https://latex.codecogs.com/svg.image?t_H=\pi\sqrt{\frac{(r_1&plus;r_2)^3}{8\mu}})
+    ///
+    pub fn compute(&self, from: &dyn Body, to: &dyn Body) -> Self {
+        let mu = SOLAR_GRAVITATIONAL_PARAMETER;
+        let r1 = from.semimajor() * ASTRONOMICAL_UNIT;
+        let r2 = to.semimajor() * ASTRONOMICAL_UNIT;
+
+        let departure_delta_v = (mu / r1).sqrt() * ((2.0 * r2 / (r1 + r2)).sqrt() - 1.0);
+        let arrival_delta_v = (mu / r2).sqrt() * (1.0 - (2.0 * r1 / (r1 + r2)).sqrt());
+        let transfer_time = std::f64::consts::PI * ((r1 + r2).powf(3.0) / (8.0 * mu)).sqrt()
+            / EARTH_ROTATIONAL_PERIOD;
+
+        let (p1, p2) = (Self::period(from), Self::period(to));
+        let synodic_period = 1.0 / (1.0 / p1 - 1.0 / p2).abs();
+
+        // the destination has to be this far ahead, so it arrives as the craft does
+        let phase_angle = 180.0 - 360.0 * transfer_time / p2;
+
+        Self {
+            departure_delta_v: departure_delta_v.abs(),
+            arrival_delta_v: arrival_delta_v.abs(),
+            transfer_time,
+            synodic_period,
+            phase_angle,
+        }
+    }
+
+    /// The combined burns in kilometers per second
+    pub fn total_delta_v(&self) -> f64 {
+        self.departure_delta_v + self.arrival_delta_v
+    }
+
+    /// This method finds the next `count` launch windows after `start` (julian date).
+    ///
+    /// Bodies with the same period never realign, so there are no windows between them.
+    /// The search gives up after a synodic period more than the windows should take,
+    /// and moons are refused as the transfer is around the sun.
+    pub fn windows(
+        &self,
+        from: &dyn Body,
+        to: &dyn Body,
+        start: f64,
+        count: usize,
+    ) -> Result<Vec<LaunchWindow>, TransferError> {
+        for body in [from, to] {
+            if body.host().is_some() {
+                return Err(TransferError::Host(body.name()));
+            }
+        }
+
+        // how far the destination leads, minus where it has to be, wrapped to (-180, 180]
+        let offset = |jd: f64| {
+            let lead = to.heliocentric_state_at(jd).longitude()
+                - from.heliocentric_state_at(jd).longitude();
+
+            180.0 - (180.0 - (lead - self.phase_angle)).rem_euclid(360.0)
+        };
+
+        if !self.synodic_period.is_finite() || !start.is_finite() {
+            return Ok(Vec::new());
+        }
+
+        let step = (self.synodic_period / 100.0).max(1.0);
+        let last = start + (count as f64 + 1.0) * self.synodic_period;
+        let mut list = Vec::new();
+        let mut julian_date = start;
+
+        while list.len() < count && julian_date < last {
+            let next = julian_date + step;
+            let (before, after) = (offset(julian_date), offset(next));
+
+            // the sign flips on alignment, while the wrap around jumps by a whole turn
+            if before.signum() != after.signum() && (after - before).abs() < 180.0 {
+                let departure = Events::refine(julian_date, next, |jd| offset(jd) < 0.0);
+
+                list.push(LaunchWindow {
+                    departure,
+                    arrival: departure + self.transfer_time,
+                });
+            }
+
+            julian_date = next;
+        }
+
+        Ok(list)
+    }
+
+    /// The orbital period in earth days
+    fn period(body: &dyn Body) -> f64 {
+        body.orbital_period() * body.rotational_period() / EARTH_ROTATIONAL_PERIOD
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains when to leave and when to arrive for a transfer.
+pub struct LaunchWindow {
+    /// ### The julian date of departure
+    pub departure: f64,
+    /// ### The julian date of arrival
+    pub arrival: f64,
+}
+
+impl LaunchWindow {
+    /// The departure in earth time
+    pub fn departure_date(&self) -> EarthDateTime {
+        Julian.jd2greg_named(self.departure)
+    }
+
+    /// The arrival in the destination body's calendar
+    pub fn arrival_date(&self, to: &mut dyn Body) -> Date {
+        to.to_date(self.arrival)
+    }
+}
+
+/// The bisections it takes to pin down the universal variable
+const BISECTIONS: usize = 200;
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This structure solves lambert's problem, the orbit between two positions in a given time.
+pub struct Lambert;
+
+impl Lambert {
+    /// This method computes the velocities (km/s) at both ends of a prograde, single revolution transfer.
+    ///
+    /// Uses the universal variable method, where `r1` and `r2` are in kilometers,
+    /// `time_of_flight` is in seconds and `mu` is the gravitational parameter of the host.
+    ///
+    /// > - ![Universal Variable](https://latex.codecogs.com/svg.image?\sqrt{\mu}\Delta&space;t=\left(\frac{y(z)}{C(z)}\right)^{3/2}S(z)&plus;A\sqrt{y(z)})
+    ///
+    pub fn solve(
+        &self,
+        r1: Vector,
+        r2: Vector,
+        time_of_flight: f64,
+        mu: f64,
+    ) -> Option<(Vector, Vector)> {
+        let (n1, n2) = (norm(r1), norm(r2));
+
+        let mut theta = (dot(r1, r2) / (n1 * n2)).clamp(-1.0, 1.0).acos();
+        if cross(r1, r2).2 < 0.0 {
+            theta = radians_in_circle() - theta;
+        }
+
+        let a = theta.sin() * (n1 * n2 / (1.0 - theta.cos())).sqrt();
+        let y = |z: f64| n1 + n2 + a * (z * stumpff_s(z) - 1.0) / stumpff_c(z).sqrt();
+        let flight = |z: f64| {
+            let y = y(z);
+
+            (y / stumpff_c(z)).powf(1.5) * stumpff_s(z) + a * y.sqrt()
+        };
+
+        // the time of flight only grows with z, up to a full revolution at 4π²
+        let target = mu.sqrt() * time_of_flight;
+        let (mut low, mut high) = (
+            -4.0 * radians_in_circle().powf(2.0),
+            radians_in_circle().powf(2.0),
+        );
+
+        for _ in 0..BISECTIONS {
+            let middle = (low + high) / 2.0;
+
+            match y(middle) < 0.0 || flight(middle) < target {
+                true => low = middle,
+                false => high = middle,
+            }
+        }
+
+        let y = y(high);
+        if !y.is_finite() || y < 0.0 {
+            return None;
+        }
+
+        // lagrange coefficients
+        let f = 1.0 - y / n1;
+        let g = a * (y / mu).sqrt();
+        let g_dot = 1.0 - y / n2;
+
+        let v1 = scale(subtract(r2, scale(r1, f)), 1.0 / g);
+        let v2 = scale(subtract(scale(r2, g_dot), r1), 1.0 / g);
+
+        Some((v1, v2))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// This data structure contains one departure and arrival pairing of a porkchop plot.
+pub struct PorkchopCell {
+    /// ### The julian date of departure
+    pub departure: f64,
+    /// ### The julian date of arrival
+    pub arrival: f64,
+    /// ### The launch energy in square kilometers per second squared
+    pub c3: f64,
+    /// ### The hyperbolic excess speed at arrival in kilometers per second
+    pub arrival_v_infinity: f64,
+}
+
+impl PorkchopCell {
+    /// The departure in the departure body's calendar
+    pub fn departure_date(&self, from: &mut dyn Body) -> Date {
+        from.to_date(self.departure)
+    }
+
+    /// The arrival in the destination body's calendar
+    pub fn arrival_date(&self, to: &mut dyn Body) -> Date {
+        to.to_date(self.arrival)
+    }
+
+    /// The time in earth days from departure to arrival
+    pub fn time_of_flight(&self) -> f64 {
+        self.arrival - self.departure
+    }
+}
+
+#[derive(Display, Debug, Clone, PartialEq, Error)]
+/// This is the collection of problems planning a transfer
+pub enum TransferError {
+    /// the {0} step of {1} days must be a positive number
+    Step(&'static str, f64),
+    /// the {0} dates from {1} to {2} must be finite
+    Bounds(&'static str, f64, f64),
+    /// {0} orbits a planet, transfers are planned around the sun
+    Host(String),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// This data structure contains the julian dates sampled along an axis of a porkchop plot.
+pub struct Sampling {
+    /// ### The first julian date
+    pub start: f64,
+    /// ### The last julian date, sampled when a step lands on it
+    pub end: f64,
+    /// ### The earth days between samples
+    pub step: f64,
+}
+
+impl Sampling {
+    /// This method samples from `start` to `end` every `step` earth days.
+    pub fn new(start: f64, end: f64, step: f64) -> Self {
+        Self { start, end, step }
+    }
+
+    /// This method lists the julian dates, `axis` names the sampling in errors.
+    pub fn dates(&self, axis: &'static str) -> Result<Vec<f64>, TransferError> {
+        if !self.start.is_finite() || !self.end.is_finite() {
+            return Err(TransferError::Bounds(axis, self.start, self.end));
+        }
+
+        if !(self.step.is_finite() && self.step > 0.0) {
+            return Err(TransferError::Step(axis, self.step));
+        }
+
+        Ok((0..)
+            .map(|index| self.start + index as f64 * self.step)
+            .take_while(|julian_date| *julian_date <= self.end)
+            .collect())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+/// This data structure contains a grid of lambert transfers for plotting porkchops.
+pub struct Porkchop {
+    /// ### The transfers, ordered by departure then arrival
+    pub cells: Vec<PorkchopCell>,
+}
+
+impl Porkchop {
+    /// This method computes the grid of transfers from one body to another.
+    ///
+    /// Pairings that arrive before they depart, or have no solution, are left out.
+    pub fn compute(
+        &self,
+        from: &dyn Body,
+        to: &dyn Body,
+        departures: Sampling,
+        arrivals: Sampling,
+    ) -> Result<Self, TransferError> {
+        let mut cells = Vec::new();
+        let (departures, arrivals) = (departures.dates("departure")?, arrivals.dates("arrival")?);
+
+        for &departure in departures.iter() {
+            let leave = from.heliocentric_state_at(departure);
+
+            for &arrival in arrivals.iter() {
+                let reach = to.heliocentric_state_at(arrival);
+                let time_of_flight = (arrival - departure) * EARTH_ROTATIONAL_PERIOD;
+
+                if time_of_flight > 0.0 {
+                    if let Some((v1, v2)) = Lambert.solve(
+                        leave.position,
+                        reach.position,
+                        time_of_flight,
+                        SOLAR_GRAVITATIONAL_PARAMETER,
+                    ) {
+                        cells.push(PorkchopCell {
+                            departure,
+                            arrival,
+                            c3: norm(subtract(v1, leave.velocity)).powf(2.0),
+                            arrival_v_infinity: norm(subtract(v2, reach.velocity)),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(Self { cells })
+    }
+
+    /// This method exports the grid as csv, one row per cell with both julian and body dates.
+    pub fn to_csv(&self, from: &mut dyn Body, to: &mut dyn Body) -> String {
+        let mut csv = String::from(
+            "departure_jd,arrival_jd,departure_date,arrival_date,time_of_flight,c3,arrival_v_infinity\n",
+        );
+
+        for cell in self.cells.iter() {
+            let leave = cell.departure_date(from);
+            let reach = cell.arrival_date(to);
+
+            csv.push_str(&format!(
+                "{},{},{}/{}/{},{}/{}/{},{},{},{}\n",
+                cell.departure,
+                cell.arrival,
+                leave.year,
+                leave.month,
+                leave.day,
+                reach.year,
+                reach.month,
+                reach.day,
+                cell.time_of_flight(),
+                cell.c3,
+                cell.arrival_v_infinity
+            ));
+        }
+
+        csv
+    }
+}
+
+/// Stumpff function C(z)
+fn stumpff_c(z: f64) -> f64 {
+    match z {
+        z if z > 0.0 => (1.0 - z.sqrt().cos()) / z,
+        z if z < 0.0 => ((-z).sqrt().cosh() - 1.0) / -z,
+        _ => 0.5,
+    }
+}
+
+/// Stumpff function S(z)
+fn stumpff_s(z: f64) -> f64 {
+    match z {
+        z if z > 0.0 => (z.sqrt() - z.sqrt().sin()) / z.powf(1.5),
+        z if z < 0.0 => ((-z).sqrt().sinh() - (-z).sqrt()) / (-z).powf(1.5),
+        _ => 1.0 / 6.0,
+    }
+}
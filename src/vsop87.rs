@@ -0,0 +1,178 @@
+use strum::{AsRefStr, VariantArray};
+
+use crate::{
+    conversions::ASTRONOMICAL_UNIT,
+    ephemeris::{Ephemeris, JULIAN_CENTURY},
+    julian::JD2NOON,
+    orbit::{norm, scale, subtract, StateVector, Vector},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// This module contains the series of earth
+mod earth;
+/// This module contains the series of jupiter
+mod jupiter;
+/// This module contains the series of mars
+mod mars;
+/// This module contains the series of mercury
+mod mercury;
+/// This module contains the series of neptune
+mod neptune;
+/// This module contains the series of saturn
+mod saturn;
+/// This module contains the series of uranus
+mod uranus;
+/// This module contains the series of venus
+mod venus;
+
+/// A periodic term `A cos(B + C τ)`, τ in julian millennia from J2000
+///
+/// ### (A, B, C) in 1e-8 units, radians and radians per millennium
+pub(crate) type Term = (f64, f64, f64);
+
+/// The days in a julian millennium
+const JULIAN_MILLENNIUM: f64 = 10.0 * JULIAN_CENTURY;
+
+/// The days either side of a julian date the velocity is differenced over
+const STEP: f64 = 0.01;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, AsRefStr, VariantArray)]
+/// This is the collection of planets with a truncated VSOP87 theory
+///
+/// The series are VSOP87D (Bretagnon & Francou, 1988) with the terms kept in
+/// Meeus' *Astronomical Algorithms* appendix III, which stay within arcseconds for the
+/// inner planets and well within an arcminute for the giant planets over centuries.
+/// Positions are heliocentric, so they carry the sun's wobble around the barycenter.
+///
+/// ```rust
+/// use rust_solar::vsop87::Vsop87;
+///
+/// // Meeus example 32.a, venus on 1992 December 20 at 0h TD
+/// let (longitude, latitude, distance) = Vsop87::Venus.coordinates(2448976.5);
+///
+/// assert!((longitude.to_degrees() - 26.11428).abs() < 1e-4);
+/// assert!((latitude.to_degrees() + 2.62070).abs() < 1e-4);
+/// assert!((distance - 0.724603).abs() < 1e-6);
+/// ```
+pub enum Vsop87 {
+    /// The first planet from the sun
+    Mercury,
+    /// The second planet from the sun
+    Venus,
+    /// The third planet from the sun, its center rather than the earth-moon barycenter
+    Earth,
+    /// The fourth planet from the sun
+    #[default]
+    Mars,
+    /// The fifth planet from the sun
+    Jupiter,
+    /// The sixth planet from the sun
+    Saturn,
+    /// The seventh planet from the sun
+    Uranus,
+    /// The eighth planet from the sun
+    Neptune,
+}
+
+impl Vsop87 {
+    /// The (longitude, latitude, distance) series, each a list of powers of τ
+    fn series(&self) -> [&'static [&'static [Term]]; 3] {
+        match self {
+            Self::Mercury => [mercury::LONGITUDE, mercury::LATITUDE, mercury::DISTANCE],
+            Self::Venus => [venus::LONGITUDE, venus::LATITUDE, venus::DISTANCE],
+            Self::Earth => [earth::LONGITUDE, earth::LATITUDE, earth::DISTANCE],
+            Self::Mars => [mars::LONGITUDE, mars::LATITUDE, mars::DISTANCE],
+            Self::Jupiter => [jupiter::LONGITUDE, jupiter::LATITUDE, jupiter::DISTANCE],
+            Self::Saturn => [saturn::LONGITUDE, saturn::LATITUDE, saturn::DISTANCE],
+            Self::Uranus => [uranus::LONGITUDE, uranus::LATITUDE, uranus::DISTANCE],
+            Self::Neptune => [neptune::LONGITUDE, neptune::LATITUDE, neptune::DISTANCE],
+        }
+    }
+
+    /// This method computes the heliocentric (longitude, latitude) in radians and distance in AU
+    /// at a julian date (TT), referred to the mean ecliptic and equinox of the date.
+    pub fn coordinates(&self, julian_date: f64) -> (f64, f64, f64) {
+        let millennia = (julian_date - JD2NOON) / JULIAN_MILLENNIUM;
+        let sum = |series: &[&[Term]]| {
+            series.iter().rev().fold(0.0, |total, terms| {
+                let power: f64 = terms
+                    .iter()
+                    .map(|(a, b, c)| a * (b + c * millennia).cos())
+                    .sum();
+
+                total * millennia + power
+            }) / 1e8
+        };
+        let [longitude, latitude, distance] = self.series();
+
+        (
+            sum(longitude).rem_euclid(std::f64::consts::TAU),
+            sum(latitude),
+            sum(distance),
+        )
+    }
+
+    /// This method computes the heliocentric position in kilometers, ecliptic J2000.
+    fn position(&self, julian_date: f64) -> Vector {
+        let (longitude, latitude, distance) = self.coordinates(julian_date);
+        let (longitude, latitude) = j2000(longitude, latitude, julian_date);
+        let distance = distance * ASTRONOMICAL_UNIT;
+
+        (
+            distance * latitude.cos() * longitude.cos(),
+            distance * latitude.cos() * longitude.sin(),
+            distance * latitude.sin(),
+        )
+    }
+}
+
+impl Ephemeris for Vsop87 {
+    fn state_at(&self, julian_date: f64) -> StateVector {
+        let position = self.position(julian_date);
+        let change = subtract(
+            self.position(julian_date + STEP),
+            self.position(julian_date - STEP),
+        );
+
+        StateVector {
+            position,
+            velocity: scale(change, 1.0 / (2.0 * STEP * EARTH_ROTATIONAL_PERIOD)),
+            distance: norm(position),
+        }
+    }
+}
+
+/// Precesses ecliptic (longitude, latitude) in radians from the equinox of a julian date to J2000
+///
+/// > Meeus, *Astronomical Algorithms*, equations 21.5 and 21.7
+fn j2000(longitude: f64, latitude: f64, julian_date: f64) -> (f64, f64) {
+    let arcseconds = |value: f64| (value / 3600.0).to_radians();
+    let centuries = (julian_date - JD2NOON) / JULIAN_CENTURY;
+    let (t, t2) = (-centuries, centuries * centuries);
+
+    let eta = arcseconds(
+        (47.0029 - 0.06603 * centuries + 0.000598 * t2) * t
+            + (-0.03302 + 0.000598 * centuries) * t * t
+            + 0.000060 * t * t * t,
+    );
+    let pi = 174.876384_f64.to_radians() + arcseconds(3289.4789 * centuries + 0.60622 * t2)
+        - arcseconds((869.8089 + 0.50491 * centuries) * t - 0.03536 * t * t);
+    let p = arcseconds(
+        (5029.0966 + 2.22226 * centuries - 0.000042 * t2) * t
+            + (1.11113 - 0.000042 * centuries) * t * t
+            - 0.000006 * t * t * t,
+    );
+
+    let (sin_eta, cos_eta) = eta.sin_cos();
+    let (sin_b, cos_b) = latitude.sin_cos();
+    let (sin_l, cos_l) = (pi - longitude).sin_cos();
+
+    let a = cos_eta * cos_b * sin_l - sin_eta * sin_b;
+    let b = cos_b * cos_l;
+    let c = cos_eta * sin_b + sin_eta * cos_b * sin_l;
+
+    (
+        (p + pi - a.atan2(b)).rem_euclid(std::f64::consts::TAU),
+        c.asin(),
+    )
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use chrono_tz::Tz;
+    use rust_solar::{
+        canonical::{Timestamp, TimestampError},
+        datetime::PlanetaryDateTime,
+        format::ParseError,
+        planets::{
+            earth::Earth,
+            mars::{Mars, Martian},
+        },
+    };
+
+    /// The landing of perseverance at jezero crater, 2021-02-18 20:55 UTC
+    const LANDING: &str = "mars:ls:36-01-12T15:54:31.188072301[HT]@2459264.372330000157708";
+
+    fn read(text: &str) -> Result<(PlanetaryDateTime<Mars>, Martian), TimestampError> {
+        PlanetaryDateTime::from_timestamp(Mars, &text.parse::<Timestamp>()?)
+    }
+
+    #[test]
+    pub fn canonical_vectors() {
+        let landing = PlanetaryDateTime::new(Mars, 2459264.37233);
+        assert_eq!(LANDING, landing.timestamp(&Martian::MTCp2).to_string());
+
+        let timestamp = LANDING.parse::<Timestamp>().unwrap();
+        assert_eq!(
+            Timestamp {
+                body: "mars".to_string(),
+                calendar: "ls".to_string(),
+                year: 36,
+                month: 1,
+                sol: 12,
+                hour: 15,
+                minute: 54,
+                second: 31,
+                nanosecond: 188_072_301,
+                zone: "HT".to_string(),
+                nanoseconds: Some(landing.nanoseconds()),
+            },
+            timestamp
+        );
+
+        // the same instant on other clocks
+        assert_eq!(
+            "mars:ls:36-01-12T12:55:00.128013610[US/Pacific]@2459264.372330000157708",
+            landing.timestamp(&Tz::US__Pacific).to_string()
+        );
+        assert_eq!(
+            "earth:ls:32-12-01T20:55:00.128013610[UTC]@2459264.372330000157708",
+            PlanetaryDateTime::new(Earth, 2459264.37233)
+                .timestamp(&Tz::UTC)
+                .to_string()
+        );
+
+        // years before the calendar's first are negative
+        let early = PlanetaryDateTime::new(Mars, 2430000.0).timestamp(&Martian::MTCn5);
+        assert_eq!(
+            "mars:ls:-7-05-23T13:12:21.207970446[AMT]@2430000.000000000000000",
+            early.to_string()
+        );
+        assert_eq!(early, early.to_string().parse::<Timestamp>().unwrap());
+    }
+
+    #[test]
+    pub fn canonical_round_trip() {
+        let start = PlanetaryDateTime::new(Mars, 2459000.5);
+
+        for step in 0..300_i64 {
+            let time = start + Duration::nanoseconds(step * 7_777_777_777_777 + step * step);
+            let zone = [Martian::MTCn5, Martian::MTC, Martian::MTCp3][step as usize % 3];
+            let text = time.timestamp(&zone).to_string();
+
+            // the text is read back to the nanosecond, and written back the same
+            let (read_back, read_zone) = read(&text).unwrap();
+            assert_eq!(time, read_back, "{text}");
+            assert_eq!(zone, read_zone);
+            assert_eq!(text, read_back.timestamp(&zone).to_string());
+
+            // without the julian date the fields find the instant to within a millisecond
+            let fields = text.split('@').next().unwrap();
+            let (found, _) = read(fields).unwrap();
+            assert!(
+                (found - time).seconds().abs() < 1e-3,
+                "{fields} {}",
+                (found - time).seconds()
+            );
+        }
+    }
+
+    #[test]
+    pub fn canonical_leniency() {
+        let (landing, _) = read(LANDING).unwrap();
+
+        // zones by any of their labels, bodies in any case, fewer decimals
+        let (zone, _) = read(&LANDING.replace("[HT]", "[MTC+2]")).unwrap();
+        assert_eq!(landing, zone);
+        let (body, _) = read(&LANDING.replace("mars:", "Mars:")).unwrap();
+        assert_eq!(landing, body);
+
+        let (short, _) = read("mars:ls:36-01-12T15:54:31.188072301[HT]@2459264.37233").unwrap();
+        assert!((short - landing).seconds().abs() < 1e-4);
+        let (seconds, _) = read("mars:ls:36-01-12T15:54:31[HT]").unwrap();
+        assert!((seconds - landing).seconds().abs() < 0.2);
+    }
+
+    #[test]
+    pub fn canonical_errors() {
+        let error = |text: &str| read(text).unwrap_err().to_string();
+
+        assert_eq!(
+            "the timestamp is of `venus`, not `mars`",
+            error(&LANDING.replace("mars:", "venus:"))
+        );
+        assert_eq!(
+            "`greg` is not a known calendar",
+            error(&LANDING.replace(":ls:", ":greg:"))
+        );
+        assert_eq!(
+            "`XT` is not a known zone",
+            error(&LANDING.replace("[HT]", "[XT]"))
+        );
+        assert_eq!(
+            "the fields `mars:ls:36-01-13T15:54:31.188072301[HT]` do not match the julian date, \
+             which is `mars:ls:36-01-12T15:54:31.188072301[HT]`",
+            error(&LANDING.replace("-12T", "-13T"))
+        );
+
+        let parse = |text: &str| text.parse::<Timestamp>().unwrap_err();
+        assert_eq!(
+            ParseError::Value("body", 0),
+            parse(":ls:36-01-12T15:54:31[HT]")
+        );
+        assert_eq!(
+            ParseError::Value("body", 4),
+            parse("mars%3:ls:36-01-12T15:54:31[HT]")
+        );
+        assert_eq!(
+            ParseError::Value("zone", 27),
+            parse("mars:ls:36-01-12T15:54:31[H%41T]")
+        );
+        assert_eq!(
+            ParseError::Literal("T".to_string(), 16),
+            parse("mars:ls:36-01-12 15:54:31[HT]")
+        );
+        assert_eq!(
+            ParseError::Range("minute", 61.0),
+            parse("mars:ls:36-01-12T15:61:31[HT]")
+        );
+        assert_eq!(
+            ParseError::Literal("[".to_string(), 35),
+            parse("mars:ls:36-01-12T15:54:31.1234567891[HT]")
+        );
+        assert_eq!(
+            ParseError::Literal("]".to_string(), 28),
+            parse("mars:ls:36-01-12T15:54:31[HT")
+        );
+        assert_eq!(
+            ParseError::Trailing("Z".to_string(), 50),
+            parse("mars:ls:36-01-12T15:54:31[HT]@2459264.372330000157Z")
+        );
+
+        // julian dates too far for the nanoseconds are refused, and far instants still write
+        assert_eq!(
+            ParseError::Range("julian date", 9999999999.0),
+            parse("mars:ls:36-01-12T15:54:31.1[HT]@9999999999.0")
+        );
+        assert_eq!(
+            ParseError::Range("julian date", -999999999999.5),
+            parse("mars:ls:36-01-12T15:54:31.1[HT]@-999999999999.5")
+        );
+        let far = Timestamp {
+            nanoseconds: Some(i128::MAX),
+            ..LANDING.parse::<Timestamp>().unwrap()
+        };
+        assert!(far.to_string().ends_with("@1969226660422097589487121.570785695668137"));
+        let before = Timestamp {
+            nanoseconds: Some(-2_451_545 * 86_400_000_000_000 - 1),
+            ..far
+        };
+        assert!(before.to_string().ends_with("@-0.000000000000012"));
+    }
+}
@@ -0,0 +1,190 @@
+#![cfg(feature = "config")]
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Tz;
+    use rust_solar::{
+        canonical::Timestamp,
+        config::{Config, ConfigError},
+        datetime::PlanetaryDateTime,
+        instant::Instant,
+        kepler::{Body, TimeZone},
+    };
+
+    const ARRAKIS: &str = r#"
+name = "Arrakis"
+epoch = 2451545.0
+semimajor = 2.3
+eccentricity = 0.05
+rotational_period = 80000.0
+obliquity = 12.0
+perihelion = 100.0
+months = ["Ix", "Harg", "Tabr", "Sietch", "Kynes", "Muad"]
+seasons = ["Bloom", "Dust", "Storm", "Still"]
+zones = [
+    { code = "AT", name = "Arrakeen Time", offset = 0.0 },
+    { code = "ST", name = "Sietch Time", offset = 3.0 },
+]
+
+[host]
+name = "Canopus"
+mass = 2.0
+luminosity = 4.0
+"#;
+
+    #[test]
+    pub fn config_toml_body() {
+        let mut arrakis = Config::toml(ARRAKIS).unwrap();
+
+        // kepler's third law around a star of two solar masses
+        let days = arrakis.orbital_period() * arrakis.rotational_period() / 86400.0;
+        assert!((days - 365.25 * (2.3_f64.powi(3) / 2.0).sqrt()).abs() < 1e-6);
+        assert_eq!(12.0, arrakis.obliquity());
+
+        // the epoch is a vernal equinox that starts the first month and season
+        let date = arrakis.to_date(2451545.0 + 1e-6);
+        assert!(date.ls < 0.01 || date.ls > 359.99);
+        assert_eq!("Ix", arrakis.name_of_month(&date));
+        assert_eq!("Bloom", date.season);
+
+        let later = arrakis.to_date(2451545.0 + days / 2.0);
+        // half a year later perihelion has passed, so more than half of the solar longitude has too
+        assert!(later.ls > 180.0 && later.ls < 200.0);
+        assert_eq!("Sietch", arrakis.name_of_month(&later));
+        assert_eq!("Storm", later.season);
+
+        // midnight at the equinox puts the sun's opposite, 12h of right ascension, overhead
+        assert!((arrakis.sidereal_time(2451545.0, 0.0) - 12.0).abs() < 1e-6);
+        let year = arrakis.orbital_period();
+        assert!((arrakis.sidereal_period() - 80000.0 * year / (year + 1.0)).abs() < 1e-6);
+
+        // four suns at perihelion (2.3 AU × 0.95), the equator sees the sun 12° × sin(100°) off overhead
+        let flux = 1361.0 * 4.0 / (2.3_f64 * 0.95).powi(2);
+        let declination = (12.0_f64.to_radians().sin() * 100.0_f64.to_radians().sin()).asin();
+        let insolation = flux * declination.cos() / std::f64::consts::PI;
+        assert!((arrakis.insolation(0.0, 100.0) - insolation).abs() < 1e-9);
+
+        let body: &dyn Body = &arrakis;
+        assert_eq!(80000.0, body.rotational_period());
+
+        let zone = arrakis.zone("Sietch Time").unwrap();
+        let prime = arrakis.zone("AT").unwrap();
+        let zone: &dyn TimeZone = &zone;
+        assert_eq!("ST", zone.now().code);
+        assert!(((zone.day_date() - prime.day_date()) * 24.0 - 3.0).abs() < 0.01);
+        assert_eq!(None, arrakis.zone("XT"));
+
+        // the same moment on the prime meridian, in the sietch and on earth
+        let sietch = Instant::at(arrakis.zone("ST").unwrap(), 9.0, 2451545.0);
+        let prime = sietch.with_zone(arrakis.zone("AT").unwrap());
+        assert!((prime.hours() - 6.0).abs() < 1e-6);
+        assert_eq!("Arrakis+3", sietch.time().offset_name);
+        assert_eq!("UTC", sietch.with_zone(Tz::UTC).time().code);
+    }
+
+    #[test]
+    pub fn config_json_body() {
+        let json = r#"{
+            "name": "Arrakis",
+            "host": { "name": "Canopus" },
+            "epoch": 2451545.0,
+            "semimajor": 1.0,
+            "eccentricity": 0.0167,
+            "rotational_period": 86400.0,
+            "obliquity": 23.44,
+            "months": ["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven", "Twelve"],
+            "zones": [{ "code": "AT", "name": "Arrakeen Time", "offset": 0.0 }]
+        }"#;
+
+        let mut arrakis = Config::json(json).unwrap();
+        assert!((arrakis.orbital_period() - 365.25).abs() < 1e-9);
+
+        let date = arrakis.to_date(2451545.0 + 100.0);
+        assert_eq!("Summer", date.season);
+        assert_eq!(4, date.month);
+    }
+
+    #[test]
+    pub fn config_canonical_names() {
+        let arrakis = Config::toml(&ARRAKIS.replace("\"Arrakis\"", "\"Arrakis: [Dune] @ 100%\"")).unwrap();
+        let time = PlanetaryDateTime::new(arrakis.clone(), 2451545.0 + 100.0);
+
+        // the characters that end the parts of a timestamp are escaped in the body's name
+        let text = time.timestamp(&Tz::UTC).to_string();
+        assert_eq!(
+            "arrakis%3A %5Bdune%5D %40 100%25:ls:12-01-108T11:58:55.815999984[UTC]@2451645.000000000000000",
+            text
+        );
+
+        let timestamp = text.parse::<Timestamp>().unwrap();
+        assert_eq!("arrakis: [dune] @ 100%", timestamp.body);
+        let (read, zone) = PlanetaryDateTime::from_timestamp::<Tz>(arrakis, &timestamp).unwrap();
+        assert_eq!((time, Tz::UTC), (read, zone));
+        assert_eq!(text, timestamp.to_string());
+    }
+
+    #[test]
+    pub fn config_errors_name_the_field() {
+        let error = |text: &str| Config::toml(text).unwrap_err().to_string();
+
+        assert_eq!(
+            "`eccentricity`: must be at least 0 and less than 1",
+            error(&ARRAKIS.replace("eccentricity = 0.05", "eccentricity = 1.2"))
+        );
+        assert_eq!(
+            "`zones[1].code`: is already used by another zone",
+            error(&ARRAKIS.replace("code = \"ST\"", "code = \"AT\""))
+        );
+        assert_eq!(
+            "`zones[1].offset`: must be between -12 and 12",
+            error(&ARRAKIS.replace("offset = 3.0", "offset = 30.0"))
+        );
+
+        // toml reads nan and inf as numbers, they are no more physical than 0
+        assert_eq!(
+            "`semimajor`: must be finite and greater than 0",
+            error(&ARRAKIS.replace("semimajor = 2.3", "semimajor = nan"))
+        );
+        assert_eq!(
+            "`rotational_period`: must be finite and greater than 0",
+            error(&ARRAKIS.replace("rotational_period = 80000.0", "rotational_period = inf"))
+        );
+        assert_eq!(
+            "`epoch`: must be a finite julian date greater than 0",
+            error(&ARRAKIS.replace("epoch = 2451545.0", "epoch = -inf"))
+        );
+        assert_eq!(
+            "`host.mass`: must be finite and greater than 0",
+            error(&ARRAKIS.replace("mass = 2.0", "mass = nan"))
+        );
+
+        // the orbit's orientation defaults to the ecliptic, but it must still be a number
+        for (name, value) in [
+            ("inclination", "nan"),
+            ("ascending_node", "inf"),
+            ("argument_of_perihelion", "-inf"),
+        ] {
+            assert_eq!(
+                format!("`{name}`: must be finite"),
+                error(&ARRAKIS.replace("perihelion = 100.0", &format!("perihelion = 100.0\n{name} = {value}")))
+            );
+        }
+
+        let wrong = error(&ARRAKIS.replace("semimajor = 2.3", "semimajor = \"far\""));
+        assert!(wrong.starts_with("`semimajor`:"), "{wrong}");
+
+        let missing = error(&ARRAKIS.replace("obliquity = 12.0\n", ""));
+        assert!(missing.contains("obliquity"), "{missing}");
+
+        let unknown = error(&ARRAKIS.replace("[host]", "[host]\nradius = 1.0"));
+        assert!(unknown.starts_with("`host"), "{unknown}");
+
+        let json = Config::json(r#"{ "name": 5 }"#).unwrap_err();
+        assert!(matches!(json, ConfigError::Parse(ref path, _) if path == "name"));
+
+        assert!(matches!(
+            Config::open("Cargo.lock"),
+            Err(ConfigError::Format)
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::Duration;
+    use rust_solar::{
+        datetime::{PlanetaryDateTime, Sols, Step},
+        julian::JD2NOON,
+        kepler::Body,
+        planets::{earth::Earth, mars::Mars},
+    };
+
+    #[test]
+    pub fn datetime_arithmetic() {
+        let start = PlanetaryDateTime::new(Mars, JD2NOON);
+        assert_eq!(JD2NOON, start.julian_date());
+
+        // a sol is 88775.245 earth seconds
+        let sol = start + Sols(1);
+        assert_eq!(Duration::milliseconds(88_775_245), (sol - start).duration);
+        assert_eq!(1.0, (sol - start).sols);
+        assert_eq!(start, sol - Sols(1));
+
+        // durations are exact to the nanosecond, both ways
+        let tick = start + Duration::nanoseconds(1);
+        assert_eq!(Duration::nanoseconds(1), (tick - start).duration);
+        assert_eq!(Duration::nanoseconds(-1), (start - tick).duration);
+        assert_eq!(start, tick - Duration::nanoseconds(1));
+
+        let mut walk = start;
+        for _ in 0..1000 {
+            walk += Duration::milliseconds(1500);
+        }
+        walk -= Sols(2);
+        assert_eq!(1500.0 - 2.0 * 88_775.245, (walk - start).seconds());
+
+        // a martian year of 668.6 sols is 686.98 earth days
+        let year = start + Sols(669);
+        let span = year - start;
+        assert!((span.seconds() / 86400.0 - 686.98).abs() < 0.6);
+        assert_eq!(669.0, span.sols.round());
+
+        // a day of the calendar is a sol after the day before
+        let mut mars = Mars;
+        let date = mars.to_date(JD2NOON + 100.0);
+        let today = PlanetaryDateTime::from_date(Mars, &date) + Duration::hours(1);
+        let tomorrow = today + Sols(1);
+        assert_eq!(date.day, today.date().day);
+        assert_eq!(date.day + 1.0, tomorrow.date().day);
+    }
+
+    #[test]
+    pub fn datetime_ordering() {
+        let start = PlanetaryDateTime::new(Earth, JD2NOON);
+        let times = [
+            start + Duration::hours(5),
+            start - Sols(3),
+            start,
+            start + Duration::hours(5),
+            start + Sols(1) - Duration::days(1),
+        ];
+
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        assert_eq!(start - Sols(3), sorted[0]);
+        assert_eq!(start + Duration::hours(5), sorted[4]);
+        assert!(start - Sols(3) < start && start <= start + Sols(0));
+
+        // earth sols are earth days, so two of the instants are the same
+        let unique = times.iter().collect::<HashSet<_>>();
+        assert_eq!(3, unique.len());
+        assert!(unique.contains(&PlanetaryDateTime::new(Earth, JD2NOON)));
+    }
+
+    #[test]
+    pub fn datetime_ranges() {
+        let start = PlanetaryDateTime::new(Mars, 2459640.0) + Duration::hours(1);
+
+        // every sol between two dates
+        let days = start
+            .range(start + Sols(10) + Duration::hours(1), Step::Sols(1))
+            .dates()
+            .map(|date| date.day)
+            .collect::<Vec<_>>();
+        assert_eq!(11, days.len());
+        assert!(days
+            .windows(2)
+            .all(|pair| pair[1] - pair[0] == 1.0 || pair[1] == 1.0));
+
+        // every month of a year, from the spring equinox
+        let equinox = start.next_solar_longitude(0.0);
+        let winter = equinox.advance(Step::SolarLongitude(345.0));
+        let months = equinox
+            .range(winter, Step::Months(1))
+            .map(|time| (time + Duration::hours(1)).date().month)
+            .collect::<Vec<_>>();
+        assert_eq!((1..=12).collect::<Vec<_>>(), months);
+
+        // months are shorter near perihelion, where the planet moves faster
+        let lengths = equinox
+            .range(winter, Step::Months(1))
+            .map(|time| (time.advance(Step::Months(1)) - time).sols)
+            .collect::<Vec<_>>();
+        let (shortest, longest) = (lengths[8], lengths[2]);
+        assert!(shortest < 48.0 && longest > 64.0, "{lengths:?}");
+
+        // every 10 Ls of a year
+        let solar_longitudes = equinox
+            .range(
+                equinox.advance(Step::SolarLongitude(355.0)),
+                Step::SolarLongitude(10.0),
+            )
+            .dates()
+            .map(|date| date.ls)
+            .collect::<Vec<_>>();
+        assert_eq!(36, solar_longitudes.len());
+        for (step, ls) in solar_longitudes.iter().enumerate() {
+            let apart = (ls - step as f64 * 10.0 + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(apart.abs() < 1e-6, "{step} {ls}");
+        }
+
+        // every year, each on the same sol of its year
+        let years = start
+            .range(start.advance(Step::Years(3)), Step::Years(1))
+            .dates()
+            .collect::<Vec<_>>();
+        assert_eq!(3, years.len());
+        assert_eq!(years[0].year + 2, years[2].year);
+        assert!(years.iter().all(|date| date.day == years[0].day));
+
+        // a step that does not move ends the range
+        assert_eq!(1, start.range(start + Sols(5), Step::Sols(0)).count());
+        assert_eq!(0, start.range(start, Step::Sols(1)).count());
+    }
+
+    #[test]
+    pub fn datetime_bounds() {
+        let start = PlanetaryDateTime::new(Mars, JD2NOON);
+
+        // julian dates that are not numbers or too far to count are refused
+        assert!(PlanetaryDateTime::try_new(Mars, f64::NAN).is_none());
+        assert!(PlanetaryDateTime::try_new(Mars, f64::INFINITY).is_none());
+        assert!(PlanetaryDateTime::try_new(Mars, 1e300).is_none());
+        assert!(PlanetaryDateTime::try_new(Mars, -1e300).is_none());
+        assert_eq!(Some(start), PlanetaryDateTime::try_new(Mars, JD2NOON));
+        assert!(std::panic::catch_unwind(|| PlanetaryDateTime::new(Mars, f64::NAN)).is_err());
+
+        // a span longer than a duration holds is held at the longest, the sols are still counted
+        let far = PlanetaryDateTime::new(Mars, 1e12);
+        let span = far - start;
+        assert_eq!(Duration::max_value(), span.duration);
+        assert!((span.sols / ((1e12 - JD2NOON) * 86400.0 / 88_775.245) - 1.0).abs() < 1e-9);
+        assert_eq!(Duration::min_value(), (start - far).duration);
+
+        // sols far beyond a duration add up exactly
+        let sols = i64::MAX / 1_000_000;
+        let later = start + Sols(sols);
+        assert!(((later - start).sols / sols as f64 - 1.0).abs() < 1e-12);
+        assert_eq!(sols as i128 * 88_775_245_000_000, later.nanoseconds() - start.nanoseconds());
+        assert_eq!(start, later - Sols(sols));
+
+        // past the last second an instant counts, it stays there
+        let last = start + Sols(i64::MAX);
+        assert_eq!(i64::MAX as i128 * 1_000_000_000 + 999_999_999, last.nanoseconds());
+        assert_eq!(last, last + Duration::max_value());
+        let first = start - Sols(i64::MAX) - Sols(i64::MAX);
+        assert_eq!(i64::MIN as i128 * 1_000_000_000, first.nanoseconds());
+    }
+}
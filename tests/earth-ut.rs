@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use icu_calendar::{chinese::Chinese, julian::Julian, Gregorian};
-    use rust_solar::{planets::earth::{EarthDate, EarthDateTime, EarthTimeZones, RustSolarCalendar}, set_datetimes};
+    use rust_solar::{daylight::{Daylight, Polar}, kepler::Body, planets::earth::{Earth, EarthDate, EarthDateTime, EarthTimeZones, RustSolarCalendar}, set_datetimes};
 
     #[test]
     fn julian2chinese_jd_epoch() {
@@ -59,7 +59,7 @@ mod tests {
         let input = EarthDate::now();
         let cal = RustSolarCalendar.construct_calendar(input, "julian");
         let cal_1 = RustSolarCalendar.to_calendar(cal.date, "julian", Chinese::default());
-        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "chinese", Gregorian::default());
+        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "chinese", Gregorian);
         println!("{:?}", cal_2);
     }
 
@@ -68,7 +68,7 @@ mod tests {
         let input = EarthDate::now();
         let cal = RustSolarCalendar.construct_calendar(input, "gregorian");
         let cal_1 = RustSolarCalendar.to_calendar(cal.date, "gregorian", Chinese::default());
-        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "chinese", Julian::default());
+        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "chinese", Julian);
         println!("{:?}", cal_2);
     }
 
@@ -76,8 +76,8 @@ mod tests {
     fn chinese2julian2gregorian() {
         let input = EarthDate::now();
         let cal = RustSolarCalendar.construct_calendar(input, "chinese");
-        let cal_1 = RustSolarCalendar.to_calendar(cal.date, "chinese", Julian::default());
-        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "julian", Gregorian::default());
+        let cal_1 = RustSolarCalendar.to_calendar(cal.date, "chinese", Julian);
+        let cal_2 = RustSolarCalendar.to_calendar(cal_1.date, "julian", Gregorian);
         println!("{:?}", cal_2);
     }
 
@@ -100,4 +100,70 @@ mod tests {
             "Japan".to_string(), "EST".to_string(), "APPLE".to_string()
         };
     }
+
+    #[test]
+    fn does_sidereal_time() {
+        // Meeus, Astronomical Algorithms example 12.a: 1987 April 10, 0h UT
+        let gmst = Earth.sidereal_time(2446895.5, 0.0);
+        assert!((gmst - 13.1795463).abs() < 1e-3);
+
+        // a sidereal day later the same stars are overhead, 3m56s before the solar day ends
+        let later = Earth.sidereal_time(2446895.5 + Earth.sidereal_period() / 86400.0, 0.0);
+        assert!((later - gmst).abs() < 1e-6);
+        assert!((Earth.rotational_period() - Earth.sidereal_period() - 235.9).abs() < 0.1);
+
+        // 77 degrees west is 5h8m behind greenwich
+        let local = Earth.sidereal_time(2446895.5, -77.0);
+        assert!((gmst - local - 77.0 / 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn does_equation_of_time() {
+        // sundials run about 16.4 minutes fast on november 3rd and 14.2 minutes slow on february 11th
+        assert!((Earth.equation_of_time(2460251.5) * 4.0 - 16.4).abs() < 0.5);
+        assert!((Earth.equation_of_time(2460351.5) * 4.0 + 14.2).abs() < 0.5);
+
+        // the sun is over the tropic of cancer on the june solstice
+        let (latitude, _) = Earth.subsolar_point(2460482.5);
+        assert!((latitude - 23.44).abs() < 0.05);
+
+        // noon at greenwich is early by the equation of time, within the two-body model's minute
+        let solar_time = Earth.solar_time(2460251.5 + 0.5, 0.0);
+        assert!((solar_time - 12.0 - Earth.equation_of_time(2460251.5 + 0.5) / 15.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn does_daylight() {
+        // london on the june solstice, the almanac gives 03:43 and 20:21 UT with refraction
+        let date = Earth.to_date(2460481.5);
+        let day = Daylight::default().compute(&Earth, &date, 51.5, -0.13);
+        let hours = |julian_date: f64| (julian_date - 0.5).fract() * 24.0;
+
+        assert!((hours(day.sunrise.unwrap()) - 3.72).abs() < 0.15);
+        assert!((hours(day.sunset.unwrap()) - 20.35).abs() < 0.15);
+        assert!((day.day_length - 16.6).abs() < 0.25);
+        assert!(day.dawn.unwrap() < day.sunrise.unwrap() && day.dusk.unwrap() > day.sunset.unwrap());
+
+        // tromsø has the midnight sun in june and the polar night in december
+        assert_eq!(Polar::Day, Daylight::default().compute(&Earth, &date, 69.65, 18.96).polar);
+        let winter = Earth.to_date(2460665.5);
+        let night = Daylight::default().compute(&Earth, &winter, 69.65, 18.96);
+        assert_eq!(Polar::Night, night.polar);
+        assert_eq!((None, 0.0), (night.sunrise, night.day_length));
+    }
+
+    #[test]
+    fn does_insolation() {
+        // the daily mean at the top of the atmosphere (W/m²)
+        assert!((Earth.insolation(0.0, 0.0) - 436.7).abs() < 1.0);
+        assert!((Earth.insolation(90.0, 90.0) - 524.2).abs() < 1.0);
+        assert_eq!(0.0, Earth.insolation(90.0, 270.0));
+
+        // the summer pole gets more than the equator, the hemispheres mirror at the equinoxes
+        assert!(Earth.insolation(90.0, 90.0) > Earth.insolation(0.0, 90.0));
+        assert!((Earth.insolation(45.0, 0.0) - Earth.insolation(-45.0, 0.0)).abs() < 1e-9);
+
+        let flux = Earth.solar_flux(2451545.0);
+        assert!(flux > 1361.0 && flux < 1415.0);
+    }
 }
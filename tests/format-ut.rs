@@ -0,0 +1,257 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use chrono_tz::Tz;
+    use rust_solar::{
+        datetime::{PlanetaryDateTime, Sols},
+        format::{Fields, FormatError, Item, ParseError, Pattern, DEFAULT_PATTERN},
+        kepler::{Body, Clock, HourType, Time},
+        planets::mars::{Mars, Martian},
+    };
+
+    // a clock whose midnight starts the sols of the calendar
+    struct Calendar;
+
+    impl Clock for Calendar {
+        fn day_length(&self) -> f64 {
+            Mars.rotational_period() / 86400.0
+        }
+
+        fn hours(&self, julian_date: f64) -> f64 {
+            Mars.orbit_day(julian_date).fract() * 24.0
+        }
+
+        fn labels(&self, _: f64) -> (String, String, String) {
+            ("CT".to_string(), "Calendar Time".to_string(), "CT".to_string())
+        }
+    }
+
+    fn fields(hour: i32) -> Fields {
+        Fields {
+            year: 37,
+            month: 6,
+            month_name: "Month 6".to_string(),
+            sol: 12,
+            sol_of_year: 287,
+            ls: 163.04,
+            season: "Summer".to_string(),
+            time: Time {
+                hour,
+                minute: 3,
+                second: 22,
+                code: "HT".to_string(),
+                name: "Hellas Time".to_string(),
+                offset_name: "MTCp2".to_string(),
+                hour_type: HourType::new(&HourType::Unknown, hour as u8),
+            },
+        }
+    }
+
+    #[test]
+    pub fn format_directives() {
+        let format =
+            |pattern: &str, hour: i32| Pattern::new(pattern).unwrap().format(&fields(hour));
+
+        assert_eq!("MY37-06-12T14:03:22 HT", format("MY%FT%T %Z", 14));
+        assert_eq!(
+            "Month 6 (06), sol 12 of the month, 287 of the year",
+            format("%B (%m), sol %d of the month, %j of the year", 14)
+        );
+        assert_eq!("Ls 163.0°, Summer", format("Ls %L°, %q", 14));
+        assert_eq!("Hellas Time 100%", format("%N 100%%", 14));
+
+        // the hour type decides the 12 hour clock
+        assert_eq!("02:03 PM", format("%I:%M %p", 14));
+        assert_eq!("12:03 AM / 00", format("%I:%M %p / %H", 0));
+        assert_eq!("12:03 PM", format("%I:%M %p", 12));
+
+        assert_eq!(
+            vec![
+                Item::Literal("sol ".to_string()),
+                Item::Sol,
+                Item::Literal(" at ".to_string()),
+                Item::Hour,
+            ],
+            Pattern::new("sol %d at %H").unwrap().items
+        );
+    }
+
+    #[test]
+    pub fn format_errors() {
+        assert_eq!(
+            Err(FormatError::Directive('x', 3)),
+            "%Y %x".parse::<Pattern>()
+        );
+        assert_eq!(
+            "`%x` at byte 3 is not a directive",
+            Pattern::new("%Y %x").unwrap_err().to_string()
+        );
+        assert_eq!(Err(FormatError::Incomplete), Pattern::new("sol 5%"));
+    }
+
+    #[test]
+    pub fn format_planetary_date_time() {
+        let landing = PlanetaryDateTime::new(Mars, 2459264.37233);
+        let (date, time) = (landing.date(), Martian::MTCp2.time(landing.julian_date()));
+
+        // the same text that is assembled by hand
+        assert_eq!(
+            format!(
+                "{}/{:02}/{:03}, {:.1}° {:02}:{:02}:{:02} {}",
+                date.year,
+                date.month,
+                date.day,
+                date.ls,
+                time.hour,
+                time.minute,
+                time.second,
+                time.code
+            ),
+            landing
+                .format(&Martian::MTCp2, "%Y/%m/%j, %L° %T %Z")
+                .unwrap()
+        );
+
+        // months start on sol 1 and count up each sol
+        let start = landing.month_start() + Duration::seconds(1);
+        assert_eq!(1, start.fields(&Martian::MTC).sol);
+        assert_eq!(date.month, start.date().month);
+
+        let fields = (0..200)
+            .map(|sol| (start + Sols(sol)).fields(&Martian::MTC))
+            .collect::<Vec<_>>();
+        for pair in fields.windows(2) {
+            match pair[0].month == pair[1].month {
+                true => assert_eq!(pair[0].sol + 1, pair[1].sol),
+                false => assert!(pair[1].sol <= 2),
+            }
+            assert_eq!("Month", &pair[1].month_name[..5]);
+        }
+
+        // the sol of the year is the day of the date
+        assert!(fields.iter().any(|fields| fields.month != date.month));
+        assert_eq!(
+            date.day as u32 + 1,
+            (landing + Sols(1)).fields(&Martian::MTC).sol_of_year
+        );
+
+        // half a second before midnight the clock shows the next sol, so the date does too
+        let rest = 1.0 - Mars.orbit_day(2459640.0).fract();
+        let midnight = PlanetaryDateTime::new(Mars, 2459640.0 + rest * Calendar.day_length());
+        let second = Duration::nanoseconds((Calendar.day_length() * 1e9) as i64);
+        let (before, after) = ((midnight - second * 7 / 10).fields(&Calendar), (midnight - second * 3 / 10).fields(&Calendar));
+        assert_eq!((23, 59, 59), (before.time.hour, before.time.minute, before.time.second));
+        assert_eq!((0, 0, 0), (after.time.hour, after.time.minute, after.time.second));
+        assert_eq!((midnight + second).date().day as u32, after.sol_of_year);
+        assert_eq!(after.sol_of_year, before.sol_of_year % Mars.orbital_period().ceil() as u32 + 1);
+    }
+
+    #[test]
+    pub fn parse_planetary_date_time() {
+        let (time, zone) = PlanetaryDateTime::parse(Mars, "MY24-06-12T14:03:22 HT").unwrap();
+        assert_eq!(Martian::MTCp2, zone);
+        assert_eq!((24, 6), (time.date().year, time.date().month));
+        assert_eq!(
+            "MY24-06-12T14:03:22 HT",
+            time.format(&zone, DEFAULT_PATTERN).unwrap()
+        );
+
+        // zones are read from their codes, names and offsets
+        for label in ["HT", "Hellas Time", "hellas time", "MTC+2", "MTCp2"] {
+            assert_eq!(Ok(Martian::MTCp2), label.parse::<Martian>());
+        }
+        assert_eq!(Ok(Martian::MTC), "MTC".parse::<Martian>());
+        assert_eq!(Ok(Martian::MTCn5), "MTC-5".parse::<Martian>());
+
+        let (named, _) = PlanetaryDateTime::<Mars>::parse_from_str::<Martian>(
+            Mars,
+            "Month 6, sol 12 of MY24 at 02:03:22 PM Hellas Time",
+            "%B, sol %d of MY%Y at %I:%M:%S %p %N",
+        )
+        .unwrap();
+        assert_eq!(time, named);
+
+        // and into an earth zone, the same sol read on the utc clock
+        let (utc, _) = PlanetaryDateTime::<Mars>::parse_from_str::<Tz>(
+            Mars,
+            "MY24-06-12T14:03:22 UTC",
+            DEFAULT_PATTERN,
+        )
+        .unwrap();
+        assert_eq!(
+            "MY24-06-12T14:03:22 UTC",
+            utc.format(&Tz::UTC, DEFAULT_PATTERN).unwrap()
+        );
+
+        // every time shown can be read back, months that start in the year before too
+        let start = PlanetaryDateTime::new(Mars, 2459000.5);
+        for step in 0..400 {
+            let time = start + Duration::seconds(step * 311_111);
+            let text = time.format(&Martian::MTCn3, DEFAULT_PATTERN).unwrap();
+            let (read, _) = PlanetaryDateTime::parse::<Martian>(Mars, &text).unwrap();
+            let apart = (read - time).seconds().abs();
+
+            assert!(apart < 1.1, "{text} {apart}");
+        }
+    }
+
+    #[test]
+    pub fn parse_errors() {
+        let error = |text: &str| {
+            PlanetaryDateTime::parse::<Martian>(Mars, text)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!("expected `-` at byte 4", error("MY24/06-12T14:03:22 HT"));
+        assert_eq!("expected `MY` at byte 0", error("24-06-12T14:03:22 HT"));
+        assert_eq!(
+            "the month 13 is out of range",
+            error("MY24-13-12T14:03:22 HT")
+        );
+        assert_eq!(
+            "the hour 25 is out of range",
+            error("MY24-06-12T25:03:22 HT")
+        );
+        assert_eq!(
+            "expected the minute at byte 14",
+            error("MY24-06-12T14:x3:22 HT")
+        );
+        assert_eq!("`XT` is not a known zone", error("MY24-06-12T14:03:22 XT"));
+        assert_eq!(
+            "expected the zone code at byte 20",
+            error("MY24-06-12T14:03:22 ")
+        );
+        assert_eq!(
+            "sol 70 at that time is not in month 6",
+            error("MY24-06-70T14:03:22 HT")
+        );
+
+        let parse = |text: &str, pattern: &str| {
+            PlanetaryDateTime::<Mars>::parse_from_str::<Martian>(Mars, text, pattern)
+        };
+        assert_eq!(
+            Err(ParseError::Trailing(" and more".to_string(), 16)),
+            Pattern::new("MY%F at %H")
+                .unwrap()
+                .parse("MY24-06-12 at 14 and more")
+                .map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::Missing("zone")),
+            parse("MY24-06-12", "MY%F").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::Missing("hour type")),
+            parse("MY24-06-12 02 HT", "MY%F %I %Z").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::Unknown("Month 40".to_string(), "month name")),
+            parse("Month 40, 12, MY24 HT", "%B, %d, MY%Y %Z").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::Pattern(FormatError::Directive('x', 0))),
+            parse("", "%x").map(|_| ())
+        );
+    }
+}
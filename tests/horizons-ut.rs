@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        ephemeris::{Ephemeris, EphemerisBody, Planetary},
+        horizons::{Horizons, HorizonsError},
+        kepler::Body,
+        planets::mars::Mars,
+    };
+
+    // These fixtures follow the layout of Horizons' output but are not an export from Horizons,
+    // the states are computed from the Planetary (Standish) mean elements of mars.
+    // They check the parser, not the crate against JPL.
+    const VECTORS: &str =
+        "*******************************************************************************
+Target body name: Mars (499)                      {source: Planetary}
+Center body name: Sun (10)                        {source: Planetary}
+*******************************************************************************
+Reference frame : Ecliptic of J2000.0
+Reference plane : ECLIPTIC
+Output units    : KM-S
+*******************************************************************************
+$$SOE
+2459252.500000000 = A.D. 2021-Feb-07 00:00:00.0000 TDB 
+ X = 2.119901841476807E+07 Y = 2.319021508470609E+08 Z = 4.339433793993989E+06
+ VX=-2.321211867114572E+01 VY= 4.263953791946514E+00 VZ= 6.588243703352432E-01
+ LT= 7.769024766503677E+02 RG= 2.329095031013013E+08 RR= 2.145059958455709E+00
+2459262.500000000 = A.D. 2021-Feb-17 00:00:00.0000 TDB 
+ X = 1.087225618785407E+06 Y = 2.346799295419461E+08 Z = 4.891052503037788E+06
+ VX=-2.331249452390252E+01 VY= 2.170102365422248E+00 VZ= 6.174075177608435E-01
+ LT= 7.829863752917395E+02 RG= 2.347334100292210E+08 RR= 2.074495068496820E+00
+2459272.500000000 = A.D. 2021-Feb-27 00:00:00.0000 TDB 
+ X =-1.903348722541609E+07 Y = 2.356610046444021E+08 Z = 5.405237732054044E+06
+ VX=-2.323411814330454E+01 VY= 1.071616873620606E-01 VZ= 5.722529758450505E-01
+ LT= 7.888462875719623E+02 RG= 2.364901675353734E+08 RR= 1.989821775928417E+00
+2459282.500000000 = A.D. 2021-Mar-09 00:00:00.0000 TDB 
+ X =-3.901224941982591E+07 Y = 2.348776860934543E+08 Z = 5.878965161785074E+06
+ VX=-2.298532924575261E+01 VY=-1.912140930662855E+00 VZ= 5.238320881404388E-01
+ LT= 7.944432776416370E+02 RG= 2.381681029457628E+08 RR= 1.892233868751420E+00
+2459292.500000000 = A.D. 2021-Mar-19 00:00:00.0000 TDB 
+ X =-5.870555744015208E+07 Y = 2.323726687173010E+08 Z = 6.309608864796055E+06
+ VX=-2.257481131934136E+01 VY=-3.876472950913671E+00 VZ= 4.725954025038007E-01
+ LT= 7.997418191174727E+02 RG= 2.397565657186185E+08 RR= 1.782901788961744E+00
+$$EOE
+";
+
+    // Mars around the start of mars year 36 in Horizons' layout, computed from the truncated VSOP87
+    // theory (Meeus, appendix III) rather than exported from Horizons. The theory is independent of the
+    // Planetary elements above and within a few arcseconds of JPL's DE ephemerides for mars.
+    const THEORY: &str = "Target body name: Mars (499)                      {source: VSOP87}
+Center body name: Sun (10)                        {source: VSOP87}
+Reference frame : Ecliptic of J2000.0
+Reference plane : ECLIPTIC
+Output units    : KM-S
+$$SOE
+2459248.500000000 = A.D. 2021-Feb-03 00:00:00.0000 TDB 
+ X = 2.922807596470847E+07 Y = 2.302723484929449E+08 Z = 4.108620904968302E+06
+ VX=-2.311991491880811E+01 VY= 5.107300469548337E+00 VZ= 6.742158601275016E-01
+ LT= 7.743898319464536E+02 RG= 2.321562311694342E+08 RR= 2.167030102447295E+00
+2459250.500000000 = A.D. 2021-Feb-05 00:00:00.0000 TDB 
+ X = 2.522854501159694E+07 Y = 2.311184397591787E+08 Z = 4.224470114498120E+06
+ VX=-2.316969253344858E+01 VY= 4.685503738225196E+00 VZ= 6.666021680818327E-01
+ LT= 7.756355715518035E+02 RG= 2.325296945077500E+08 RR= 2.155357940316972E+00
+2459252.500000000 = A.D. 2021-Feb-07 00:00:00.0000 TDB 
+ X = 2.122106345753791E+07 Y = 2.318916903165872E+08 Z = 4.338988617259926E+06
+ VX=-2.321194550702435E+01 VY= 4.264257204408446E+00 VZ= 6.588148230576836E-01
+ LT= 7.768744040494029E+02 RG= 2.329010871472556E+08 RR= 2.143065275603608E+00
+2459254.500000000 = A.D. 2021-Feb-09 00:00:00.0000 TDB 
+ X = 1.720692646507363E+07 Y = 2.325922057785408E+08 Z = 4.452146748407910E+06
+ VX=-2.324673303838871E+01 VY= 3.843682415610938E+00 VZ= 6.508577855285559E-01
+ LT= 7.781059745402282E+02 RG= 2.332703026919004E+08 RR= 2.130161679621107E+00
+2459256.500000000 = A.D. 2021-Feb-11 00:00:00.0000 TDB 
+ X = 1.318741885718949E+07 Y = 2.332201125372046E+08 Z = 4.563915526221050E+06
+ VX=-2.327411559213565E+01 VY= 3.423898324939525E+00 VZ= 6.427350013675297E-01
+ LT= 7.793299336456431E+02 RG= 2.336372364006042E+08 RR= 2.116656735394054E+00
+2459258.500000000 = A.D. 2021-Feb-13 00:00:00.0000 TDB 
+ X = 9.163814904934676E+06 Y = 2.337755573177287E+08 Z = 4.674266649492079E+06
+ VX=-2.329415479505809E+01 VY= 3.005021318120675E+00 VZ= 6.344504008431815E-01
+ LT= 7.805459375124857E+02 RG= 2.340017851887825E+08 RR= 2.102560031492918E+00
+$$EOE
+";
+
+    const CSV: &str = "Reference plane : ECLIPTIC
+Output units    : KM-S
+JDTDB, Calendar Date (TDB), X, Y, Z, VX, VY, VZ,
+**************************************************************************************************************************
+$$SOE
+2459252.500000000, A.D. 2021-Feb-07 00:00:00.0000,  2.119901841476807E+07,  2.319021508470609E+08,  4.339433793993989E+06, -2.321211867114572E+01,  4.263953791946514E+00,  6.588243703352432E-01,
+2459262.500000000, A.D. 2021-Feb-17 00:00:00.0000,  1.087225618785407E+06,  2.346799295419461E+08,  4.891052503037788E+06, -2.331249452390252E+01,  2.170102365422248E+00,  6.174075177608435E-01,
+2459272.500000000, A.D. 2021-Feb-27 00:00:00.0000, -1.903348722541609E+07,  2.356610046444021E+08,  5.405237732054044E+06, -2.323411814330454E+01,  1.071616873620606E-01,  5.722529758450505E-01,
+$$EOE
+";
+
+    const ELEMENTS: &str = "Target body name: Mars (499)                      {source: Planetary}
+Reference plane : ECLIPTIC
+Output units    : AU-D
+$$SOE
+2459252.500000000 = A.D. 2021-Feb-07 00:00:00.0000 TDB 
+ EC= 9.341073258453114E-02 QR= 1.381382974358843E+00 IN= 1.847975552188227E+00
+ OM= 4.949780010885763E+01 W = 2.866523498181198E+02 Tp=  2459064.954135552049
+ N = 5.240329277204655E-01 MA= 9.828020842850992E+01 TA= 1.086408060350136E+02
+ A = 1.523714237536619E+00 AD= 1.666045500714395E+00 PR= 6.869797315332721E+02
+$$EOE
+";
+
+    #[test]
+    pub fn horizons_vectors_interpolate() {
+        let table = Horizons.vectors(VECTORS).unwrap();
+        assert_eq!(5, table.rows.len());
+
+        // halfway between the first two rows
+        let state = table.state_at(2459257.5);
+        assert!((state.position.0 - 11153945.016).abs() < 1000.0);
+        assert!((state.position.1 - 233517208.169).abs() < 1000.0);
+        assert!((state.velocity.0 + 23.285).abs() < 0.001);
+
+        let csv = Horizons.vectors(CSV).unwrap();
+        assert_eq!(3, csv.rows.len());
+        assert_eq!(table.rows[2].1.position, csv.rows[2].1.position);
+    }
+
+    #[test]
+    pub fn horizons_elements() {
+        let mut mars = Horizons.elements(ELEMENTS).unwrap().remove(0);
+
+        assert_eq!("Mars (499)", mars.name);
+        assert!((mars.semimajor - 1.5237).abs() < 1.0e-4);
+
+        // the elements place mars where the vectors do
+        let table = Horizons.vectors(VECTORS).unwrap();
+        let distance = mars.state_at(2459252.5).distance_to(&table.rows[0].1);
+        assert!(distance < 1.0e4);
+
+        println!("Mars: {:?}", mars.to_date(2459252.5));
+    }
+
+    #[test]
+    pub fn horizons_table_as_ephemeris() {
+        let table = EphemerisBody {
+            body: Mars,
+            ephemeris: Horizons.vectors(VECTORS).unwrap(),
+        };
+        let source = EphemerisBody {
+            body: Mars,
+            ephemeris: Planetary::Mars,
+        };
+
+        // the interpolated table gives back the Ls of the elements it was computed from
+        for day in 0..40 {
+            let julian_date = 2459252.5 + day as f64;
+            let difference =
+                table.solar_longitude(julian_date) - source.solar_longitude(julian_date);
+
+            let difference = (difference + 180.0).rem_euclid(360.0) - 180.0;
+
+            assert!(difference.abs() < 1e-3, "{julian_date}: {difference}");
+        }
+    }
+
+    #[test]
+    pub fn horizons_mars_year_36() {
+        let table = EphemerisBody {
+            body: Mars,
+            ephemeris: Horizons.vectors(THEORY).unwrap(),
+        };
+
+        // Allison & McEwen's Ls is fitted to JPL's DE405 within 0.01°, the table agrees within 0.015°
+        for hour in 0..240 {
+            let julian_date = 2459248.5 + hour as f64 / 24.0;
+            let difference = table.solar_longitude(julian_date) - Mars.solar_longitude(julian_date);
+            let difference = (difference + 180.0).rem_euclid(360.0) - 180.0;
+
+            assert!(difference.abs() < 0.015, "{julian_date}: {difference}");
+        }
+
+        // mars year 36 began at the northern spring equinox (Ls 0) on 2021 February 7,
+        // the table and Allison & McEwen place it within an hour of each other
+        let equinox = |ls: &dyn Fn(f64) -> f64| {
+            let (mut low, mut high) = (2459252.5, 2459253.5);
+            for _ in 0..40 {
+                let middle = (low + high) / 2.0;
+                match ls(middle) > 180.0 {
+                    true => low = middle,
+                    false => high = middle,
+                }
+            }
+
+            high
+        };
+        let (tabulated, fitted) = (
+            equinox(&|julian_date| table.solar_longitude(julian_date)),
+            equinox(&|julian_date| Mars.solar_longitude(julian_date)),
+        );
+
+        assert!(tabulated > 2459252.5 && tabulated < 2459253.5);
+        assert!((tabulated - fitted).abs() < 1.0 / 24.0, "{}", (tabulated - fitted) * 24.0);
+    }
+
+    #[test]
+    pub fn horizons_rejects_malformed_output() {
+        assert!(matches!(
+            Horizons.vectors("no data here"),
+            Err(HorizonsError::NoData)
+        ));
+        assert!(matches!(
+            Horizons.vectors(&VECTORS.replace(" VZ= 6.588", " VQ= 6.588")),
+            Err(HorizonsError::Field(_, "VZ"))
+        ));
+        assert!(matches!(
+            Horizons.elements(&ELEMENTS.replace("ECLIPTIC", "FRAME")),
+            Err(HorizonsError::Plane)
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        ephemeris::EphemerisBody,
+        kepler::Body,
+        moons::io::Io,
+        planets::{earth::Earth, jupiter::Jupiter},
+        transfer::{Hohmann, TransferError},
+        vsop87::Vsop87,
+    };
+
+    #[test]
+    pub fn io_orbits_jupiter() {
+        let host = Io.host().map(|host| host.name());
+        assert_eq!(host.as_deref(), Some("Jupiter"));
+
+        // 421,800 km from jupiter at 17.33 km/s
+        let state = Io.state_at(2451545.0);
+        assert!((state.distance / 421_800.0 - 1.0).abs() < 0.005);
+        assert!((state.speed() - 17.334).abs() < 0.1);
+
+        // the sun sees io within its orbit of jupiter
+        let (io, jupiter) = (
+            Io.heliocentric_state_at(2451545.0),
+            Jupiter.heliocentric_state_at(2451545.0),
+        );
+        assert!((io.distance_to(&jupiter) - state.distance).abs() < 1e-6);
+    }
+
+    #[test]
+    pub fn io_precesses_on_jupiter_equator() {
+        // io orbits in jupiter's equator, tilted 2.2° against the ecliptic
+        let (inclination, _, _) = Io.equatorial_elements().unwrap();
+        assert!(inclination < 1e-3);
+        assert_eq!(None, Jupiter.equatorial_elements());
+
+        // the node regresses once every ~7.4 years, mostly from jupiter's oblateness
+        let (argument, node) = Io.precession();
+        assert!((-360.0 / node * 100.0 / 7.42 - 1.0).abs() < 0.05);
+        assert!(argument > 0.0);
+
+        // turning about jupiter's pole keeps io on the equator a century later
+        let (right_ascension, declination) =
+            (Jupiter.pole().0.to_radians(), Jupiter.pole().1.to_radians());
+        let obliquity = 23.4392911f64.to_radians();
+        let pole = (
+            declination.cos() * right_ascension.cos(),
+            declination.cos() * right_ascension.sin() * obliquity.cos()
+                + declination.sin() * obliquity.sin(),
+            declination.sin() * obliquity.cos()
+                - declination.cos() * right_ascension.sin() * obliquity.sin(),
+        );
+
+        for julian_date in [2451545.0, 2451545.0 + 36525.0] {
+            let state = Io.state_at(julian_date);
+            let (x, y, z) = state.position;
+            let height = x * pole.0 + y * pole.1 + z * pole.2;
+
+            assert!((height / state.distance).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn io_seasons_follow_jupiter() {
+        // the two-body seasons from the jovian equinox of 1997 stay within 0.2° of the theory
+        let theory = EphemerisBody {
+            body: Jupiter,
+            ephemeris: Vsop87::Jupiter,
+        };
+        for julian_date in (2448000..2456000).step_by(500).map(f64::from) {
+            let difference =
+                Jupiter.solar_longitude(julian_date) - theory.solar_longitude(julian_date);
+            assert!(((difference + 180.0).rem_euclid(360.0) - 180.0).abs() < 0.2);
+            assert_eq!(
+                Io.solar_longitude(julian_date),
+                Jupiter.solar_longitude(julian_date)
+            );
+        }
+    }
+
+    #[test]
+    pub fn io_has_no_launch_windows() {
+        // hohmann transfers are planned around the sun, so a moon is refused
+        let transfer = Hohmann::default().compute(&Earth, &Io);
+        let windows = transfer.windows(&Earth, &Io, 2451545.0, 3);
+
+        assert_eq!(Err(TransferError::Host("Io".to_string())), windows.map(|_| ()));
+    }
+}
@@ -1,12 +1,25 @@
 #[cfg(test)]
 mod tests {
-    use chrono::Datelike;
+    use chrono::{Datelike, TimeZone as _, Timelike};
+    use chrono_tz::Tz;
     use rust_solar::{
-        conversions::military2standard,
-        julian::Julian,
-        kepler::{Body, TimeZone},
-        planets::mars::{Mars, Martian},
+        conversions::{military2standard, ASTRONOMICAL_UNIT},
+        daylight::{Daylight, Polar, Sundial},
+        ephemeris::{Ephemeris, EphemerisBody, Planetary},
+        events::{EventError, Events, Kind},
+        instant::Instant,
+        julian::{Julian, JD2NOON},
+        kepler::{Body, Clock, Time, TimeZone, ZoneMap},
+        light::LightError,
+        orbit::StateVector,
+        planets::{
+            earth::Earth,
+            mars::{Mars, Martian, Site},
+        },
+        transfer::{Hohmann, Porkchop, Sampling, TransferError},
+        vsop87::Vsop87,
     };
+    use strum::{EnumProperty, VariantArray};
 
     #[test]
     pub fn mars_to_date() {
@@ -116,4 +129,471 @@ mod tests {
 
         println!("Coordinated Time: {:?}", value);
     }
+
+    #[test]
+    pub fn mars_state_at_j2000() {
+        let state = Mars.state_at(JD2NOON);
+        let (x, y, z) = state.position;
+
+        // JPL Horizons puts mars at 1.391 AU, 359.4° on 2000-01-01 12:00 TT
+        assert!(((x * x + y * y + z * z).sqrt() - state.distance).abs() < 1.0);
+        assert!((state.distance / ASTRONOMICAL_UNIT - 1.391).abs() < 0.01);
+        assert!((state.longitude() - 359.4).abs() < 1.0);
+
+        println!("State: {:?}, {:?} km/s", state, state.speed());
+    }
+
+    #[test]
+    pub fn mars_signal_delays() {
+        // two earth years covers both a solar conjunction and an opposition
+        let delays = Mars.signal_delays(JD2NOON, JD2NOON + 730.0, 10.0, true).unwrap();
+
+        for delay in delays.iter() {
+            // mars is between 3 and 22 light minutes away from earth
+            assert!(delay.one_way > 180.0 && delay.one_way < 1340.0);
+            assert!((delay.round_trip - 2.0 * delay.one_way).abs() < 1.0);
+        }
+
+        println!("Delays: {:?}", delays.first());
+
+        // steps that would never reach the end are refused
+        assert_eq!(Err(LightError::Step(0.0)), Mars.signal_delays(JD2NOON, JD2NOON + 730.0, 0.0, true).map(|_| ()));
+        assert_eq!(Err(LightError::Step(-10.0)), Mars.signal_delays(JD2NOON, JD2NOON + 730.0, -10.0, true).map(|_| ()));
+        assert!(matches!(Mars.signal_delays(JD2NOON, f64::INFINITY, 10.0, true), Err(LightError::Bounds(..))));
+        assert!(Mars.signal_delays(JD2NOON + 1.0, JD2NOON, 10.0, true).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn mars_conjunctions_2020_2022() {
+        // 2020-01-01 to 2023-01-01
+        let events = Events.search(&Mars, 2458849.5, 2459945.5, 10.0).unwrap();
+        let kinds: Vec<Kind> = events.iter().map(|event| event.kind).collect();
+
+        assert_eq!(
+            vec![
+                Kind::Opposition,
+                Kind::SuperiorConjunction,
+                Kind::Opposition
+            ],
+            kinds
+        );
+
+        // opposition on 2020-10-13, solar conjunction on 2021-10-08
+        assert!((events[0].interval.0 - 2459136.5).abs() < 1.5);
+        assert!((events[1].interval.0 - 2459495.6).abs() < 1.5);
+
+        let blackouts = Events.blackouts(&Mars, 2458849.5, 2459945.5, 5.0, 3.0).unwrap();
+
+        assert_eq!(1, blackouts.len());
+        assert!(blackouts[0].interval.0 < events[1].interval.0);
+        assert!(blackouts[0].interval.1 > events[1].interval.0);
+
+        println!("Blackout: {:?}", blackouts[0].dates(&mut Mars));
+
+        // steps that would never reach the end are refused
+        assert_eq!(Err(EventError::Step(0.0)), Events.search(&Mars, 2458849.5, 2459945.5, 0.0).map(|_| ()));
+        assert_eq!(Err(EventError::Step(-5.0)), Events.blackouts(&Mars, 2458849.5, 2459945.5, -5.0, 3.0).map(|_| ()));
+        assert_eq!(Err(EventError::Step(f64::INFINITY)), Events.search(&Mars, 2458849.5, 2459945.5, f64::INFINITY).map(|_| ()));
+        assert!(matches!(Events.blackouts(&Mars, f64::NAN, 2459945.5, 5.0, 3.0), Err(EventError::Bounds(..))));
+    }
+
+    #[test]
+    pub fn mars_hohmann_from_earth() {
+        let transfer = Hohmann::default().compute(&Earth, &Mars);
+
+        // the textbook earth to mars transfer is ~5.6 km/s over ~259 days
+        assert!((transfer.total_delta_v() - 5.6).abs() < 0.1);
+        assert!((transfer.transfer_time - 259.0).abs() < 2.0);
+        assert!((transfer.synodic_period - 780.0).abs() < 1.0);
+
+        let windows = transfer.windows(&Earth, &Mars, 2458849.5, 3).unwrap();
+
+        assert_eq!(3, windows.len());
+        for pair in windows.windows(2) {
+            let gap = pair[1].departure - pair[0].departure;
+
+            // eccentric orbits make the gap wander around the synodic period
+            assert!((gap - transfer.synodic_period).abs() < 60.0);
+        }
+
+        println!(
+            "Depart: {:?}, Arrive: {:?}",
+            windows[0].departure_date(),
+            windows[0].arrival_date(&mut Mars)
+        );
+    }
+
+    #[test]
+    pub fn mars_hohmann_to_itself() {
+        // the same orbit never realigns, so the synodic period is endless
+        let transfer = Hohmann::default().compute(&Mars, &Mars);
+
+        assert!(transfer.synodic_period.is_infinite());
+        assert!(transfer.windows(&Mars, &Mars, 2458849.5, 3).unwrap().is_empty());
+
+        // a body that is nowhere never aligns, the search stops after the windows' synodic periods
+        struct Nowhere;
+        impl Ephemeris for Nowhere {
+            fn state_at(&self, _julian_date: f64) -> StateVector {
+                StateVector { position: (f64::NAN, f64::NAN, f64::NAN), ..Default::default() }
+            }
+        }
+        let lost = EphemerisBody { body: Mars, ephemeris: Nowhere };
+        let transfer = Hohmann::default().compute(&Earth, &lost);
+        assert!(transfer.windows(&Earth, &lost, 2458849.5, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn mars_porkchop_perseverance() {
+        // perseverance left on 2020-07-30 and landed on 2021-02-18 with a C3 of ~14.5 km²/s²
+        let grid = Porkchop::default()
+            .compute(
+                &Earth,
+                &Mars,
+                Sampling::new(2459040.5, 2459080.5, 20.0),
+                Sampling::new(2459243.5, 2459283.5, 20.0),
+            )
+            .unwrap();
+
+        assert_eq!(9, grid.cells.len());
+
+        let launch = grid.cells[4];
+        assert!((launch.c3 - 14.5).abs() < 2.0);
+        assert!(launch.arrival_v_infinity > 2.0 && launch.arrival_v_infinity < 3.5);
+
+        let csv = grid.to_csv(&mut Earth, &mut Mars);
+        assert_eq!(10, csv.lines().count());
+
+        println!("{csv}");
+    }
+
+    #[test]
+    pub fn mars_porkchop_steps() {
+        let arrivals = Sampling::new(2459243.5, 2459283.5, 20.0);
+
+        for step in [0.0, -20.0, f64::NAN, f64::INFINITY] {
+            let departures = Sampling::new(2459040.5, 2459080.5, step);
+            let error = Porkchop::default()
+                .compute(&Earth, &Mars, departures, arrivals)
+                .unwrap_err();
+
+            assert!(matches!(error, TransferError::Step("departure", _)));
+        }
+
+        let departures = Sampling::new(2459040.5, f64::NAN, 20.0);
+        let error = Porkchop::default()
+            .compute(&Earth, &Mars, departures, arrivals)
+            .unwrap_err();
+        assert!(matches!(error, TransferError::Bounds("departure", _, _)));
+    }
+
+    #[test]
+    pub fn mars_ephemeris_solar_longitude() {
+        let mut mars = EphemerisBody {
+            body: Mars,
+            ephemeris: Planetary::Mars,
+        };
+
+        // mars year 36 began on 2021-02-07
+        let ls = mars.to_date(2459252.5).ls;
+        assert!(!(0.5..=359.5).contains(&ls));
+
+        let state = mars.state_at(JD2NOON);
+        assert!((state.distance / ASTRONOMICAL_UNIT - 1.391).abs() < 0.005);
+
+        for planet in Planetary::VARIANTS {
+            let (a, e) = (planet.elements()[0].0, planet.elements()[1].0);
+            let distance = planet.state_at(JD2NOON).distance / ASTRONOMICAL_UNIT;
+
+            assert!(distance >= a * (1.0 - e) - 0.01 && distance <= a * (1.0 + e) + 0.01);
+        }
+    }
+
+    #[test]
+    pub fn mars_secular_precession() {
+        // perihelion ls drifts by about 0.65 degrees per century (Allison & McEwen, 2000)
+        let drift = Mars.perihelion_at(JD2NOON + 36525.0).perihelion - Mars.perihelion().perihelion;
+        assert!((drift - 0.6489).abs() < 1e-3);
+        assert_eq!(Mars.perihelion_at(JD2NOON).perihelion, Mars.perihelion().perihelion);
+
+        // the node regresses so the orbit plane turns over long ranges
+        let before = Mars.state_at(JD2NOON);
+        let after = Mars.state_at(JD2NOON + 365250.0);
+        assert!((before.distance - after.distance).abs() / before.distance < 0.2);
+        assert!(after.position != before.position);
+    }
+
+    #[test]
+    pub fn mars_sidereal_time() {
+        // Mars24 worked example: 2000-01-06 00:00 UTC, α_FMS 272.7457° and MTC 23.9942h
+        let sidereal = Mars.sidereal_time(2451549.50074, 0.0);
+        assert!((sidereal - 6.1772).abs() < 1e-3);
+
+        // the sol is longer than the sidereal day by a sol per martian year
+        let sols = Mars.orbital_period();
+        let sidereal_period = Mars.rotational_period() * sols / (sols + 1.0);
+        assert!((sidereal_period - Mars.sidereal_period()).abs() < 1.0);
+
+        // olympus mons (226.2°E) sees the sky 15h04m ahead of airy-0
+        let olympus = Mars.sidereal_time(2451549.50074, 226.2);
+        assert!(((olympus - sidereal).rem_euclid(24.0) - 226.2 / 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn mars_local_solar_time() {
+        // Allison & McEwen (2000) / Mars24 worked example: 2000-01-06 00:00 UTC
+        let julian_date = 2451549.50074;
+        assert!((Mars.solar_longitude(julian_date) - 277.18758).abs() < 1e-4);
+        assert!((Mars.coordinated_time(julian_date) - 23.99425).abs() < 1e-3);
+
+        // EOT is -5.18774°, so a sundial runs 20m45s behind the mean sun
+        let (lmst, ltst) = (Mars.lmst(julian_date, 0.0), Mars.ltst(julian_date, 0.0));
+        assert!((ltst - lmst + 5.18774 / 15.0).abs() < 1e-4);
+
+        // viking 1 lander at 47.95137°W
+        assert!((Mars.lmst(julian_date, -47.95137) - 20.79745).abs() < 1e-3);
+        assert!((Mars.ltst(julian_date, -47.95137) - 20.45160).abs() < 1e-3);
+    }
+
+    #[test]
+    pub fn mars_solar_longitude_agrees() {
+        // dates, the body and the planetary theory read the same Ls from 2000 to 2030
+        let theory = EphemerisBody {
+            body: Mars,
+            ephemeris: Vsop87::Mars,
+        };
+
+        for julian_date in (2451545..2462502).step_by(97).map(f64::from) {
+            let ls = Mars.solar_longitude(julian_date);
+            assert_eq!(ls, Mars.to_date(julian_date).ls);
+            assert_eq!(ls, Body::solar_longitude(&Mars, julian_date));
+
+            let difference = theory.solar_longitude(julian_date) - ls;
+            assert!(((difference + 180.0).rem_euclid(360.0) - 180.0).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    pub fn mars_subsolar_point() {
+        // Mars24 worked example: the sun is over 24.98°S, 174.72°W
+        let julian_date = 2451549.50074;
+        let (latitude, longitude) = Mars.subsolar_point(julian_date);
+        assert!((latitude + 24.978).abs() < 1e-3);
+        assert!((longitude + 174.725).abs() < 1e-2);
+
+        // the subsolar meridian agrees with local true solar time everywhere
+        for east_longitude in [-137.4, 0.0, 77.5, 175.5] {
+            let solar_time = Mars.solar_time(julian_date, east_longitude);
+            assert!((solar_time - Mars.ltst(julian_date, east_longitude)).abs() < 1e-3);
+        }
+
+        // the ephemeris solar longitude gives the equation of time within 0.15°
+        let ephemeris = EphemerisBody {
+            body: Mars,
+            ephemeris: Planetary::Mars,
+        };
+        for day in [0.0, 200.0, 400.0] {
+            let difference = ephemeris.equation_of_time(julian_date + day)
+                - Mars.equation_of_time(julian_date + day);
+            assert!(difference.abs() < 0.15);
+        }
+    }
+
+    #[test]
+    pub fn mars_daylight() {
+        // Ls 277, the southern summer
+        let date = Mars.to_date(2451549.50074);
+        let day = |latitude: f64| Daylight::default().compute(&Mars, &date, latitude, 137.4);
+
+        // noon is when a sundial reads 12
+        let equator = day(0.0);
+        assert!((Mars.ltst(equator.noon, 137.4) - 12.0).abs() < 1e-3);
+        assert!((equator.day_length - 12.0).abs() < 0.1);
+
+        // the southern days are longer and rise earlier on the elysium clock
+        let (south, north) = (day(-20.0), day(20.0));
+        assert!(south.day_length > 13.0 && north.day_length < 11.0);
+        let elysium = |julian_date: f64| Mars.zone_time(julian_date, Martian::MTCp4);
+        assert!(elysium(south.sunrise.unwrap()) < elysium(north.sunrise.unwrap()));
+
+        // the same day read on the local clocks and a zone
+        let sundial = Sundial { body: &Mars, longitude: 137.4, mean: false };
+        let local = equator.local(&sundial);
+        assert_eq!((12, 0, "LTST"), (local.noon.hour, local.noon.minute, local.noon.code.as_str()));
+        assert!(local.sunrise.unwrap().hour == 5 && local.sunset.unwrap().hour == 18);
+        let mean = equator.local(&Sundial { mean: true, ..sundial });
+        assert!((mean.noon.hour * 60 + mean.noon.minute as i32 - 12 * 60).abs() <= 60);
+        let zone = south.local(&Martian::MTCp4);
+        assert_eq!("MTCp4", zone.sunrise.unwrap().offset_name);
+
+        assert_eq!(Polar::Day, day(-80.0).polar);
+        assert_eq!(Polar::Night, day(80.0).polar);
+    }
+
+    #[test]
+    pub fn mars_insolation() {
+        // about 717 W/m² at perihelion and 493 W/m² at aphelion
+        let fluxes: Vec<f64> = (0..687)
+            .map(|day| Mars.solar_flux(JD2NOON + day as f64))
+            .collect();
+        let most = fluxes.iter().cloned().fold(0.0, f64::max);
+        let least = fluxes.iter().cloned().fold(f64::MAX, f64::min);
+        assert!((most - 717.0).abs() < 5.0 && (least - 493.0).abs() < 5.0);
+
+        // the ground under the sun gets it all, the night side nothing
+        let julian_date = 2451549.50074;
+        let (latitude, longitude) = Mars.subsolar_point(julian_date);
+        let overhead = Mars.irradiance(julian_date, latitude, longitude);
+        assert!((overhead - Mars.solar_flux(julian_date)).abs() < 1e-6);
+        assert_eq!(0.0, Mars.irradiance(julian_date, -latitude, longitude + 180.0));
+
+        // the southern summer near perihelion outshines the northern summer
+        assert!(Mars.insolation(-60.0, 270.0) > Mars.insolation(60.0, 90.0) * 1.3);
+        assert_eq!(0.0, Mars.insolation(80.0, 270.0));
+    }
+
+    #[test]
+    pub fn mars_mission_clocks() {
+        // the last sols that were heard from, 2010 March 22 and 2018 June 10 at noon UTC
+        assert_eq!(2210, Site::Spirit.sol(2455278.0));
+        assert_eq!(5111, Site::Opportunity.sol(2458280.0));
+
+        // curiosity and perseverance land on sol 0, the rovers and pathfinder on sol 1
+        assert_eq!(0, Site::Curiosity.sol(Site::Curiosity.landing()));
+        assert_eq!(1, Site::Pathfinder.sol(Site::Pathfinder.landing()));
+        assert_eq!(-1, Site::Perseverance.sol(Site::Perseverance.landing() - 1.03));
+
+        // perseverance reached sol 1000 on 2023 December 12 (UTC)
+        let start = Site::Perseverance.sol_start(1000);
+        assert!(start > 2460290.5 && start < 2460291.5);
+        assert!(Site::Perseverance.lmst(start) < 1e-6 || Site::Perseverance.lmst(start) > 24.0 - 1e-6);
+        assert_eq!(1000, Site::Perseverance.sol(start + 0.01));
+        assert_eq!(999, Site::Perseverance.sol(start - 0.01));
+
+        // curiosity landed in the mid afternoon
+        let landing = Site::Curiosity.landing();
+        assert!(Site::Curiosity.lmst(landing) > 14.5 && Site::Curiosity.lmst(landing) < 15.5);
+        assert!((Site::Curiosity.ltst(landing) - Mars.ltst(landing, 137.44)).abs() < 1e-9);
+
+        for site in Site::VARIANTS {
+            let (latitude, longitude) = site.location();
+            assert!(latitude.abs() < 90.0 && (0.0..360.0).contains(&longitude));
+            assert!(!site.get_str("Mission").unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    pub fn mars_zone_conversion() {
+        // 14:00 in utopia is 01:30 in marineris, 12.5 hours west
+        let utopia = Martian::MTCp3.time(JD2NOON);
+        let utopia = Time {
+            hour: 14,
+            minute: 0,
+            second: 0,
+            ..utopia
+        };
+        let instant = Instant::from_time(Martian::MTCp3, &utopia, JD2NOON);
+        let marineris = instant.with_zone(Martian::MTCn2).time();
+
+        assert!((instant.julian_date - JD2NOON).abs() < 0.52);
+        assert_eq!(
+            (1, 30, 0),
+            (marineris.hour, marineris.minute, marineris.second)
+        );
+        assert_eq!(
+            ("MT", "Marineris Time"),
+            (marineris.code.as_str(), marineris.name.as_str())
+        );
+        assert_eq!("AM", marineris.hour_type);
+
+        // every zone reads the coordinated time shifted by its offset
+        let coordinated = instant.with_zone(Martian::MTC).hours();
+        assert!((coordinated - Mars.coordinated_time(instant.julian_date)).abs() < 1e-9);
+        assert!((coordinated - 6.5).abs() < 1e-6);
+
+        // noon on the us pacific coast, daylight time in july
+        let noon = Tz::US__Pacific
+            .with_ymd_and_hms(2024, 7, 1, 12, 0, 0)
+            .unwrap();
+        let pacific = Instant::from_date_time(&noon);
+        let hellas = pacific.with_zone(Martian::MTCp2);
+        let back = Instant::at(Tz::US__Pacific, 12.0, hellas.julian_date - 0.3);
+
+        assert!((back.julian_date - pacific.julian_date).abs() < 1e-6);
+        assert_eq!(0, (back.date_time() - noon).num_milliseconds());
+        assert_eq!(19, hellas.with_zone(Tz::UTC).date_time().hour());
+        assert_eq!("UTC-07:00", pacific.time().offset_name);
+
+        // the utc clock runs 64.184 seconds behind terrestrial time at j2000, 69.184 since 2017
+        let j2000 = Instant::new(JD2NOON, Tz::UTC).date_time();
+        assert_eq!((11, 58, 55), (j2000.hour(), j2000.minute(), j2000.second()));
+        assert!((j2000.timestamp_subsec_nanos() as f64 / 1e6 - 816.0).abs() < 1e-3);
+        let behind = |julian_date: f64| {
+            let date_time = Instant::new(julian_date, Tz::UTC).date_time();
+            (julian_date - 2440587.5) * 86400.0 - date_time.timestamp_millis() as f64 / 1e3
+        };
+        assert!((behind(2443144.5) - 47.184).abs() < 1e-3);
+        assert!((behind(2460492.5) - 69.184).abs() < 1e-3);
+
+        // dates beyond the chrono calendar are held at its ends rather than panic
+        assert_eq!(chrono::DateTime::<chrono::Utc>::MAX_UTC, Instant::new(1e12, Tz::UTC).date_time());
+        assert!(Tz::UTC.hours(-1e12).is_finite());
+
+        // and 14:00 hellas time is shown on the pacific clock
+        let hellas = Instant::at(Martian::MTCp2, 14.0, pacific.julian_date);
+        let shown = hellas.with_zone(Tz::US__Pacific);
+        assert_eq!(14, hellas.time().hour);
+        assert_eq!("PDT", shown.time().code);
+        assert!(
+            (shown.julian_date - pacific.julian_date).abs() <= Martian::MTCp2.day_length() / 2.0
+        );
+    }
+
+    #[test]
+    pub fn mars_zone_from_longitude() {
+        assert_eq!(Some(Martian::MTCp2), Martian::from_longitude(77.45));
+        assert_eq!(Some(Martian::MTCp3), Martian::from_longitude(100.0 + 360.0));
+        assert_eq!((54.0, 90.0), Martian::MTCp2.bounds());
+
+        // a bound belongs to the zone that starts there
+        assert_eq!(Some(Martian::MTCp1), Martian::from_longitude(18.0));
+        assert_eq!(Some(Martian::MTC), Martian::from_longitude(-18.0));
+
+        // the date line belongs to amazonis, arcadia ends just before it
+        assert_eq!(Some(Martian::MTCn5), Martian::from_longitude(-180.0));
+        assert_eq!(Some(Martian::MTCn5), Martian::from_longitude(180.0));
+        assert_eq!(Some(Martian::MTCp5), Martian::from_longitude(179.999));
+        assert_eq!(None, Martian::from_longitude(f64::NAN));
+
+        // every longitude has exactly one zone
+        for step in 0..720 {
+            let longitude = -180.0 + step as f64 * 0.5;
+            let zones = Martian::VARIANTS.iter().filter(|z| z.contains(longitude));
+            assert_eq!(1, zones.count());
+        }
+
+        // zones may wrap across the date line
+        assert_eq!(Some(Hemisphere::Far), Hemisphere::from_longitude(-170.0));
+        assert_eq!(Some(Hemisphere::Far), Hemisphere::from_longitude(180.0));
+        assert_eq!(Some(Hemisphere::Near), Hemisphere::from_longitude(0.0));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Hemisphere {
+        Near,
+        Far,
+    }
+
+    impl ZoneMap for Hemisphere {
+        fn zones() -> &'static [Self] {
+            &[Self::Near, Self::Far]
+        }
+
+        fn bounds(&self) -> (f64, f64) {
+            match self {
+                Self::Near => (-90.0, 90.0),
+                Self::Far => (90.0, -90.0),
+            }
+        }
+    }
 }
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::ASTRONOMICAL_UNIT,
+        kepler::Body,
+        mpc::{Mpc, MpcError},
+    };
+
+    const MPCORB: &str = "MINOR PLANET CENTER ORBIT DATABASE (MPCORB)
+Des'n     H     G   Epoch     M        Peri.      Node       Incl.       e            n           a        Reference #Obs #Opp    Arc    rms  Perts   Computer
+----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------
+00001   3.34   0.15 K2555 188.70269   73.27343   80.25221   10.58780  0.0794013  0.21424651   2.7660512  0 E2024-V47  7330 125 1801-2024 0.80 M-v 30k MPCLINUX   4000 (1) Ceres                   20241101
+";
+
+    const COMETELS: &str = "0001P         1986 02 09.4589  0.574642  0.967990  111.8657   59.0858  162.1877  19860205   -1.0  4.0 1P/Halley                                                98, 1083
+";
+
+    #[test]
+    pub fn mpc_asteroids() {
+        let mut ceres = Mpc.asteroids(MPCORB).unwrap().bodies.remove(0);
+
+        assert_eq!("(1) Ceres", ceres.name);
+        // K2555 is 2025-05-05 00:00 TT
+        assert_eq!(2460800.5, ceres.epoch);
+        assert!((ceres.orbital_period() / 365.25 - 4.6).abs() < 0.05);
+
+        let distance = ceres.state_at(ceres.epoch).distance / ASTRONOMICAL_UNIT;
+        assert!(distance > 2.766 * (1.0 - 0.0794) && distance < 2.766 * (1.0 + 0.0794));
+
+        println!("Ceres: {:?}", ceres.to_date(ceres.epoch));
+    }
+
+    #[test]
+    pub fn mpc_comets() {
+        let halley = Mpc.comets(COMETELS).unwrap().bodies.remove(0);
+
+        assert_eq!("1P/Halley", halley.name);
+        assert!((halley.semimajor - 17.95).abs() < 0.05);
+        assert!((halley.orbital_period() / 365.25 - 76.0).abs() < 0.5);
+
+        // halley is at perihelion when it passes perihelion
+        let distance = halley.state_at(halley.perihelion_time).distance / ASTRONOMICAL_UNIT;
+        assert!((distance - 0.5746).abs() < 0.01);
+    }
+
+    #[test]
+    pub fn mpc_rejects_malformed_lines() {
+        let broken = MPCORB.replace("0.0794013", "0.07x4013");
+
+        assert!(matches!(
+            Mpc.asteroids(&broken),
+            Err(MpcError::Column(4, "eccentricity"))
+        ));
+        assert!(matches!(
+            Mpc.asteroids(&MPCORB.replace("K2555", "Z2555")),
+            Err(MpcError::Epoch(4, _))
+        ));
+    }
+
+    #[test]
+    pub fn mpc_skips_unbound_comets() {
+        // the real file mixes returning comets with parabolic and hyperbolic ones
+        let parabolic = COMETELS
+            .replace("0.967990", "1.000000")
+            .replace("1P/Halley", "C/1995 O1");
+        let hyperbolic = COMETELS
+            .replace("0.967990", "3.356191")
+            .replace("1P/Halley", "2I/Borisov");
+        let mixed = format!("{parabolic}{COMETELS}\n{hyperbolic}");
+
+        let catalog = Mpc.comets(&mixed).unwrap();
+        let names: Vec<&str> = catalog.bodies.iter().map(|body| body.name.as_str()).collect();
+        assert_eq!(vec!["1P/Halley"], names);
+        assert_eq!(
+            vec![(1, "C/1995 O1".to_string(), 1.0), (4, "2I/Borisov".to_string(), 3.356191)],
+            catalog.unbound
+        );
+
+        let catalog = Mpc.asteroids(&MPCORB.replace("0.0794013", "1.0794013")).unwrap();
+        assert!(catalog.bodies.is_empty());
+        assert_eq!(vec![(4, "(1) Ceres".to_string(), 1.0794013)], catalog.unbound);
+    }
+}
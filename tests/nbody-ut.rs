@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::{ASTRONOMICAL_UNIT, GRAVITATIONAL_CONSTANT, SOLAR_GRAVITATIONAL_PARAMETER},
+        julian::JD2NOON,
+        kepler::Body,
+        nbody::{Integrator, NbodyError, Particle, System},
+        orbit::{OrbitalElements, StateVector},
+        planets::{mars::Mars, EARTH_ROTATIONAL_PERIOD},
+    };
+
+    fn system(integrator: Integrator, jupiter: bool) -> System {
+        let mut particles = vec![
+            Particle {
+                name: "Sun".to_string(),
+                mass: SOLAR_GRAVITATIONAL_PARAMETER / GRAVITATIONAL_CONSTANT,
+                ..Default::default()
+            },
+            Particle {
+                name: "Terra".to_string(),
+                mass: 5.972e24,
+                position: (ASTRONOMICAL_UNIT, 0.0, 0.0),
+                velocity: (0.0, 29.78, 0.0),
+            },
+        ];
+
+        if jupiter {
+            particles.push(Particle {
+                name: "Giant".to_string(),
+                mass: 1.898e27 * 20.0,
+                position: (-2.0 * ASTRONOMICAL_UNIT, 0.0, 0.0),
+                velocity: (0.0, -21.06, 0.0),
+            });
+        }
+
+        System {
+            particles,
+            julian_date: JD2NOON,
+            integrator,
+            step: 3600.0,
+            tolerance: 1e-10,
+        }
+    }
+
+    #[test]
+    pub fn nbody_osculating_elements() {
+        // the two-body model's own gravitational parameter, from kepler's third law
+        let period = Mars.orbital_period() * Mars.rotational_period();
+        let gravitational_parameter =
+            4.0 * std::f64::consts::PI.powi(2) * (Mars.semimajor() * ASTRONOMICAL_UNIT).powi(3)
+                / period.powi(2);
+
+        let state = Mars.state_at(JD2NOON);
+        let elements = OrbitalElements::from_state(
+            "Mars".to_string(),
+            JD2NOON,
+            &state,
+            gravitational_parameter,
+        );
+
+        assert!((elements.semimajor - Mars.semimajor()).abs() < 1e-6);
+        assert!((elements.eccentricity - Mars.orbital_eccentricity()).abs() < 1e-6);
+        assert!((elements.inclination - Mars.inclination()).abs() < 1e-6);
+        assert!((elements.ascending_node - Mars.ascending_node()).abs() < 1e-6);
+        assert!((elements.argument_of_perihelion - Mars.argument_of_perihelion()).abs() < 1e-4);
+    }
+
+    #[test]
+    pub fn nbody_two_body_year() {
+        let mut leapfrog = system(Integrator::Leapfrog, false);
+        let energy = leapfrog.energy();
+        let mut terra = leapfrog
+            .orbit(
+                1,
+                0,
+                JD2NOON + 3.0 * 365.25,
+                1.0,
+                EARTH_ROTATIONAL_PERIOD,
+                (0.0, 90.0),
+            )
+            .unwrap();
+
+        assert!(((leapfrog.energy() - energy) / energy).abs() < 1e-8);
+        assert!((terra.body.period - 365.25).abs() < 0.5);
+        assert!((terra.semimajor() - 1.0).abs() < 0.01);
+
+        // a year later the calendar starts over at the same solar longitude
+        let (start, after) = (
+            terra.to_date(JD2NOON),
+            terra.to_date(JD2NOON + terra.body.period),
+        );
+        assert_eq!(start.year + 1, after.year);
+        assert!((start.ls - after.ls).abs() < 0.1);
+
+        let mut adaptive = system(Integrator::Adaptive, false);
+        adaptive.advance(JD2NOON + 3.0 * 365.25).unwrap();
+        let (a, b) = (&adaptive.particles[1], &leapfrog.particles[1]);
+        let apart = StateVector {
+            position: a.position,
+            ..Default::default()
+        }
+        .distance_to(&StateVector {
+            position: b.position,
+            ..Default::default()
+        });
+        assert!(apart < 1e5);
+    }
+
+    #[test]
+    pub fn nbody_perturbed_year() {
+        let mut lonely = system(Integrator::Adaptive, false);
+        let mut perturbed = system(Integrator::Adaptive, true);
+
+        let alone = lonely
+            .orbit(
+                1,
+                0,
+                JD2NOON + 10.0 * 365.25,
+                5.0,
+                EARTH_ROTATIONAL_PERIOD,
+                (0.0, 90.0),
+            )
+            .unwrap();
+        let with = perturbed
+            .orbit(
+                1,
+                0,
+                JD2NOON + 10.0 * 365.25,
+                5.0,
+                EARTH_ROTATIONAL_PERIOD,
+                (0.0, 90.0),
+            )
+            .unwrap();
+
+        // a heavy giant outside drags the year away from the two-body period
+        assert!((alone.body.period - with.body.period).abs() > 0.5);
+        assert!(with.orbital_period() > 300.0 && with.orbital_period() < 450.0);
+    }
+
+    #[test]
+    pub fn nbody_rejects_bad_steps() {
+        // the default system has no step, so it never gets anywhere
+        assert_eq!(
+            Err(NbodyError::Step(0.0)),
+            System::default().advance(JD2NOON + 1.0)
+        );
+
+        let mut leapfrog = system(Integrator::Leapfrog, false);
+        assert_eq!(
+            Err(NbodyError::Interval(0.0)),
+            leapfrog.track(1, 0, JD2NOON + 10.0, 0.0).map(|_| ())
+        );
+        assert_eq!(
+            Err(NbodyError::Interval(-1.0)),
+            leapfrog.track(1, 0, JD2NOON + 10.0, -1.0).map(|_| ())
+        );
+        assert!(matches!(
+            leapfrog.track(1, 0, f64::NAN, 1.0),
+            Err(NbodyError::Date(_))
+        ));
+
+        // only two particles, and a body cannot orbit itself
+        assert_eq!(
+            Err(NbodyError::Particle(2, 2)),
+            leapfrog.track(2, 0, JD2NOON + 10.0, 1.0).map(|_| ())
+        );
+        assert_eq!(
+            Err(NbodyError::Particle(5, 2)),
+            leapfrog.track(1, 5, JD2NOON + 10.0, 1.0).map(|_| ())
+        );
+        assert_eq!(
+            Err(NbodyError::Host(1)),
+            leapfrog.track(1, 1, JD2NOON + 10.0, 1.0).map(|_| ())
+        );
+        assert!(matches!(
+            leapfrog.orbit(3, 0, JD2NOON + 10.0, 1.0, EARTH_ROTATIONAL_PERIOD, (0.0, 90.0)),
+            Err(NbodyError::Particle(3, 2))
+        ));
+
+        // the adaptive integrator needs an error to aim for
+        for tolerance in [0.0, -1e-10, f64::NAN] {
+            let mut adaptive = system(Integrator::Adaptive, false);
+            adaptive.tolerance = tolerance;
+            assert!(matches!(
+                adaptive.advance(JD2NOON + 1.0),
+                Err(NbodyError::Tolerance(_))
+            ));
+        }
+
+        // two particles on top of each other have no finite error to adapt to
+        let mut collided = system(Integrator::Adaptive, false);
+        collided.particles[1].position = (0.0, 0.0, 0.0);
+        assert!(matches!(
+            collided.advance(JD2NOON + 1.0),
+            Err(NbodyError::Diverged(_))
+        ));
+    }
+}
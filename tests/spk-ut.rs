@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_solar::{
+        ephemeris::{Ephemeris, EphemerisBody},
+        julian::JD2NOON,
+        kepler::Body,
+        planets::mars::Mars,
+        spk::{Kernel, KernelError},
+    };
+
+    /// Builds a trimmed kernel with the sun fixed at the barycenter,
+    /// and the mars barycenter moving 1 km/s along x from 2.0e8 km.
+    fn fixture() -> Vec<u8> {
+        let radius: f64 = 1.0e6;
+        let mut words: Vec<f64> = vec![0.0; 3 * 128];
+
+        // file record
+        let mut bytes = vec![0u8; 1024];
+        bytes[0..8].copy_from_slice(b"DAF/SPK ");
+        bytes[8..12].copy_from_slice(&2i32.to_le_bytes());
+        bytes[12..16].copy_from_slice(&6i32.to_le_bytes());
+        bytes[76..80].copy_from_slice(&2i32.to_le_bytes());
+        bytes[80..84].copy_from_slice(&2i32.to_le_bytes());
+        bytes[88..96].copy_from_slice(b"LTL-IEEE");
+
+        // (target, center, x coefficients) with one record of degree 1
+        let segments = [(10, 0, [0.0, 0.0]), (4, 0, [2.0e8, radius])];
+        let mut summaries = Vec::new();
+
+        for (target, center, x) in segments {
+            let start = words.len() + 1;
+            words.extend([0.0, radius, x[0], x[1], 0.0, 0.0, 0.0, 0.0]);
+            words.extend([-radius, 2.0 * radius, 8.0, 1.0]);
+            summaries.push((target, center, start, words.len()));
+        }
+
+        // summary record
+        let mut summary = vec![0u8; 1024];
+        summary[16..24].copy_from_slice(&(summaries.len() as f64).to_le_bytes());
+        for (index, (target, center, start, end)) in summaries.iter().enumerate() {
+            let at = 24 + index * 40;
+            summary[at..at + 8].copy_from_slice(&(-radius).to_le_bytes());
+            summary[at + 8..at + 16].copy_from_slice(&radius.to_le_bytes());
+            for (n, int) in [*target, *center, 17, 2, *start as i32, *end as i32]
+                .iter()
+                .enumerate()
+            {
+                summary[at + 16 + n * 4..at + 20 + n * 4].copy_from_slice(&int.to_le_bytes());
+            }
+        }
+
+        bytes.extend(summary);
+        bytes.extend(vec![b' '; 1024]);
+        bytes.extend(words[3 * 128..].iter().flat_map(|word| word.to_le_bytes()));
+        bytes
+    }
+
+    #[test]
+    pub fn spk_reads_segments() {
+        let kernel = Kernel::from_bytes(fixture()).unwrap();
+
+        assert_eq!(2, kernel.segments.len());
+        assert_eq!(4, kernel.segments[1].target);
+        assert_eq!(2, kernel.segments[1].data_type);
+    }
+
+    #[test]
+    pub fn spk_evaluates_chebyshev() {
+        let kernel = Arc::new(Kernel::from_bytes(fixture()).unwrap());
+        let mars = EphemerisBody {
+            body: Mars,
+            ephemeris: kernel.ephemeris(4).unwrap(),
+        };
+
+        // one day after J2000 the x position has moved 86400 km
+        let state = mars.state_at(JD2NOON + 1.0);
+        assert!((state.position.0 - 2.000864e8).abs() < 1.0e-3);
+        assert!((state.velocity.0 - 1.0).abs() < 1.0e-9);
+        assert!((mars.ephemeris.state_at(JD2NOON).unwrap().distance - 2.0e8).abs() < 1.0e-3);
+
+        // the fixture covers a million seconds either side of J2000
+        assert!(matches!(
+            mars.ephemeris.state_at(JD2NOON + 20.0),
+            Err(KernelError::NotCovered(4, _))
+        ));
+        assert!(Ephemeris::state_at(&mars.ephemeris, JD2NOON + 20.0)
+            .distance
+            .is_nan());
+    }
+
+    #[test]
+    pub fn spk_rejects_bad_kernels() {
+        let kernel = Arc::new(Kernel::from_bytes(fixture()).unwrap());
+
+        assert!(matches!(
+            Kernel::from_bytes(vec![0; 2048]),
+            Err(KernelError::NotSpk)
+        ));
+        assert!(matches!(
+            kernel.ephemeris(399),
+            Err(KernelError::NotCovered(399, _))
+        ));
+        assert!(matches!(
+            Kernel::open("missing.bsp"),
+            Err(KernelError::Io(_))
+        ));
+
+        println!("{}", kernel.ephemeris(399).unwrap_err());
+    }
+
+    #[test]
+    pub fn spk_rejects_corrupt_kernels() {
+        let corrupt = |at: usize, value: &[u8]| {
+            let mut bytes = fixture();
+            bytes[at..at + value.len()].copy_from_slice(value);
+            Kernel::from_bytes(bytes)
+        };
+        // the mars summary starts 40 bytes after the sun's, its integers after 2 doubles
+        let mars = 1024 + 24 + 40 + 16;
+
+        // a summary record that links to itself
+        assert!(matches!(
+            corrupt(1024, &2.0f64.to_le_bytes()),
+            Err(KernelError::Corrupt("summary records"))
+        ));
+        assert!(matches!(
+            corrupt(1024 + 16, &1.0e9f64.to_le_bytes()),
+            Err(KernelError::Corrupt("summary records"))
+        ));
+        assert!(matches!(
+            corrupt(mars + 16, &0i32.to_le_bytes()),
+            Err(KernelError::Corrupt("segment addresses"))
+        ));
+
+        // mars as its own center never reaches the barycenter
+        let kernel = corrupt(mars + 4, &4i32.to_le_bytes()).unwrap();
+        assert!(matches!(
+            kernel.barycentric_state(4, JD2NOON),
+            Err(KernelError::Cycle(4))
+        ));
+
+        // an empty directory has no records to read
+        let kernel = Kernel::from_bytes(fixture()).unwrap();
+        let records = (kernel.segments[1].address.1 - 1) * 8;
+        let kernel = corrupt(records, &0.0f64.to_le_bytes()).unwrap();
+        assert!(matches!(
+            kernel.barycentric_state(4, JD2NOON),
+            Err(KernelError::Corrupt("segment directory"))
+        ));
+
+        // a segment that ends before its directory
+        let kernel = corrupt(mars + 20, &2i32.to_le_bytes()).unwrap();
+        assert!(matches!(
+            kernel.barycentric_state(4, JD2NOON),
+            Err(KernelError::Corrupt("segment addresses"))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::ASTRONOMICAL_UNIT,
+        ephemeris::{Ephemeris, EphemerisBody, Planetary},
+        kepler::Body,
+        orbit::StateVector,
+        planets::mars::Mars,
+        vsop87::Vsop87,
+    };
+    use strum::VariantArray;
+
+    /// The angle in arcminutes between two directions seen from the earth
+    fn separation(earth: &StateVector, first: &StateVector, second: &StateVector) -> f64 {
+        let toward = |state: &StateVector| {
+            (
+                state.position.0 - earth.position.0,
+                state.position.1 - earth.position.1,
+                state.position.2 - earth.position.2,
+            )
+        };
+        let (a, b) = (toward(first), toward(second));
+        let dot = a.0 * b.0 + a.1 * b.1 + a.2 * b.2;
+        let length = |v: (f64, f64, f64)| (v.0 * v.0 + v.1 * v.1 + v.2 * v.2).sqrt();
+
+        (dot / length(a) / length(b)).acos().to_degrees() * 60.0
+    }
+
+    #[test]
+    pub fn vsop87_meeus_examples() {
+        // Meeus example 25.b, the earth on 1992 October 13 at 0h TD
+        let (longitude, latitude, distance) = Vsop87::Earth.coordinates(2448908.5);
+        assert!((longitude.to_degrees() - 19.907372).abs() < 1e-6);
+        assert!((latitude.to_degrees() + 0.000179).abs() < 1e-6);
+        assert!((distance - 0.99760775).abs() < 1e-8);
+
+        // Meeus example 32.a, venus on 1992 December 20 at 0h TD
+        let (longitude, latitude, distance) = Vsop87::Venus.coordinates(2448976.5);
+        assert!((longitude.to_degrees() - 26.11428).abs() < 1e-5);
+        assert!((latitude.to_degrees() + 2.62070).abs() < 1e-5);
+        assert!((distance - 0.724603).abs() < 1e-6);
+    }
+
+    #[test]
+    pub fn vsop87_great_conjunction() {
+        // jupiter passed 6.1' from saturn on 2020 December 21 near 18:30 TT
+        let light_day = ASTRONOMICAL_UNIT / 299_792.458 / 86400.0;
+        let closest = (0..288)
+            .map(|step| 2459204.5 + step as f64 / 96.0)
+            .map(|julian_date| {
+                let earth = Vsop87::Earth.state_at(julian_date);
+                let seen = |planet: Vsop87| {
+                    let distance = planet.state_at(julian_date).distance_to(&earth);
+                    planet.state_at(julian_date - distance / ASTRONOMICAL_UNIT * light_day)
+                };
+
+                (
+                    separation(&earth, &seen(Vsop87::Jupiter), &seen(Vsop87::Saturn)),
+                    julian_date,
+                )
+            })
+            .fold((f64::MAX, 0.0), |best, next| match next.0 < best.0 {
+                true => next,
+                false => best,
+            });
+
+        assert!((closest.0 - 6.1).abs() < 0.05);
+        assert!((closest.1 - 2459205.27).abs() < 0.05);
+    }
+
+    #[test]
+    pub fn vsop87_mars_solar_longitude() {
+        // Allison & McEwen's Ls is fitted to the JPL ephemeris within 0.01° in the 20th and 21st centuries,
+        // measured from the equinox of date as the pole precesses
+        let mars = EphemerisBody {
+            body: Mars,
+            ephemeris: Vsop87::Mars,
+        };
+
+        for year in (1900..=2100).step_by(10) {
+            let julian_date = 2415020.5 + (year - 1900) as f64 * 365.25;
+            let difference = mars.solar_longitude(julian_date) - Mars.solar_longitude(julian_date);
+
+            assert!(((difference + 180.0).rem_euclid(360.0) - 180.0).abs() < 0.015);
+        }
+    }
+
+    #[test]
+    pub fn vsop87_against_mean_elements() {
+        // the mean elements stay within a quarter degree and 0.3% of the theory from 1800 to 2050,
+        // jupiter and saturn wander most as they pull on each other
+        for (planet, elements) in Vsop87::VARIANTS.iter().zip(Planetary::VARIANTS) {
+            for julian_date in (2378497..2469807).step_by(3653).map(f64::from) {
+                let (theory, mean) = (planet.state_at(julian_date), elements.state_at(julian_date));
+                let difference =
+                    (theory.longitude() - mean.longitude() + 180.0).rem_euclid(360.0) - 180.0;
+
+                assert!(difference.abs() < 0.25, "{planet:?} {julian_date}");
+                assert!((theory.distance - mean.distance).abs() / mean.distance < 3e-3);
+            }
+        }
+
+        // the velocity follows the position
+        let state = Vsop87::Mars.state_at(2451545.0);
+        let later = Vsop87::Mars.state_at(2451545.0 + 1.0 / 24.0);
+        let moved = state.distance_to(&later) / 3600.0;
+        assert!((moved - state.speed()).abs() < 1e-3);
+    }
+}